@@ -1,11 +1,11 @@
-//! Implementation of the `ExtClientValidationContext` trait for the `Context`
-//! type.
+//! Implementation of the `ExtClientValidationContext` and
+//! `TmValidationContext` traits for the `Context` type.
+use ibc_client_tendermint::context::TmValidationContext;
 use ibc_core::client::context::prelude::*;
 use ibc_core::client::types::error::ClientError;
 use ibc_core::client::types::Height;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::ClientId;
-use ibc_core::host::types::path::ClientConsensusStatePath;
 use ibc_core::primitives::proto::Any;
 use ibc_core::primitives::Timestamp;
 
@@ -49,11 +49,7 @@ where
 
         match next_height {
             Some(h) => {
-                let cons_state_path = ClientConsensusStatePath::new(
-                    client_id.clone(),
-                    h.revision_number(),
-                    h.revision_height(),
-                );
+                let cons_state_path = h.consensus_state_path(client_id);
                 self.consensus_state(&cons_state_path).map(Some)
             }
             None => Ok(None),
@@ -69,14 +65,18 @@ where
 
         match prev_height {
             Some(prev_height) => {
-                let cons_state_path = ClientConsensusStatePath::new(
-                    client_id.clone(),
-                    prev_height.revision_number(),
-                    prev_height.revision_height(),
-                );
+                let cons_state_path = prev_height.consensus_state_path(client_id);
                 self.consensus_state(&cons_state_path).map(Some)
             }
             None => Ok(None),
         }
     }
 }
+
+/// The Tendermint-specific methods keep their default implementations.
+impl<'a, C: ClientType<'a>> TmValidationContext for Context<'a, C>
+where
+    <C::ClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    <C::ConsensusState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+}
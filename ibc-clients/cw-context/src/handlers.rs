@@ -1,7 +1,6 @@
 use cosmwasm_std::{to_json_binary, Binary};
 use ibc_core::client::context::prelude::*;
 use ibc_core::client::types::error::ClientError;
-use ibc_core::host::types::path::ClientConsensusStatePath;
 use ibc_core::primitives::proto::Any;
 use prost::Message;
 
@@ -62,11 +61,7 @@ where
             SudoMsg::VerifyMembership(msg) => {
                 let msg = VerifyMembershipMsg::try_from(msg)?;
 
-                let client_cons_state_path = ClientConsensusStatePath::new(
-                    self.client_id(),
-                    msg.height.revision_number(),
-                    msg.height.revision_height(),
-                );
+                let client_cons_state_path = msg.height.consensus_state_path(&self.client_id());
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
@@ -83,11 +78,7 @@ where
             SudoMsg::VerifyNonMembership(msg) => {
                 let msg = VerifyNonMembershipMsg::try_from(msg)?;
 
-                let client_cons_state_path = ClientConsensusStatePath::new(
-                    client_id.clone(),
-                    msg.height.revision_number(),
-                    msg.height.revision_height(),
-                );
+                let client_cons_state_path = msg.height.consensus_state_path(&client_id);
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
@@ -103,11 +94,9 @@ where
             SudoMsg::VerifyUpgradeAndUpdateState(msg) => {
                 let msg = VerifyUpgradeAndUpdateStateMsg::try_from(msg)?;
 
-                let client_cons_state_path = ClientConsensusStatePath::new(
-                    client_id.clone(),
-                    client_state.latest_height().revision_number(),
-                    client_state.latest_height().revision_height(),
-                );
+                let client_cons_state_path = client_state
+                    .latest_height()
+                    .consensus_state_path(&client_id);
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
@@ -131,12 +120,11 @@ where
             SudoMsg::MigrateClientStore(_) => {
                 self.set_substitute_prefix();
                 let substitute_client_state = self.client_state(&client_id)?;
-                let substitute_consensus_state =
-                    self.consensus_state(&ClientConsensusStatePath::new(
-                        client_id.clone(),
-                        substitute_client_state.latest_height().revision_number(),
-                        substitute_client_state.latest_height().revision_height(),
-                    ))?;
+                let substitute_consensus_state = self.consensus_state(
+                    &substitute_client_state
+                        .latest_height()
+                        .consensus_state_path(&client_id),
+                )?;
 
                 let substitute_client_state_any = substitute_client_state.into();
 
@@ -171,11 +159,7 @@ where
                 QueryResponse::success().genesis_metadata(self.get_metadata()?)
             }
             QueryMsg::TimestampAtHeight(msg) => {
-                let client_cons_state_path = ClientConsensusStatePath::new(
-                    client_id,
-                    msg.height.revision_number(),
-                    msg.height.revision_height(),
-                );
+                let client_cons_state_path = msg.height.consensus_state_path(&client_id);
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
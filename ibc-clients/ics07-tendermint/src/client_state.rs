@@ -10,7 +10,7 @@
 
 use ibc_client_tendermint_types::error::Error;
 use ibc_client_tendermint_types::proto::v1::ClientState as RawTmClientState;
-use ibc_client_tendermint_types::ClientState as ClientStateType;
+use ibc_client_tendermint_types::{ClientState as ClientStateType, DecodeOptions};
 use ibc_core_client::types::error::ClientError;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
@@ -39,6 +39,17 @@ impl ClientState {
     pub fn inner(&self) -> &ClientStateType {
         &self.0
     }
+
+    /// Decodes a client state from an [`Any`] like the `TryFrom` conversion,
+    /// applying the checks and type URL aliases enabled in `options`.
+    pub fn try_from_any_with_options(
+        raw: Any,
+        options: &DecodeOptions,
+    ) -> Result<Self, ClientError> {
+        Ok(Self(ClientStateType::try_from_any_with_options(
+            raw, options,
+        )?))
+    }
 }
 
 impl Protobuf<RawTmClientState> for ClientState {}
@@ -63,7 +74,7 @@ impl TryFrom<Any> for ClientState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        Ok(Self(ClientStateType::try_from(raw)?))
+        Self::try_from_any_with_options(raw, &DecodeOptions::default())
     }
 }
 
@@ -109,7 +120,7 @@ mod tests {
             unbonding_period: Duration::new(128_000, 0),
             max_clock_drift: Duration::new(3, 0),
             latest_height: Height::new(1, 10).expect("Never fails"),
-            proof_specs: ProofSpecs::cosmos(),
+            proof_specs: ProofSpecs::cosmos_sdk(),
             upgrade_path: Vec::new(),
             allow_update: AllowUpdate {
                 after_expiry: false,
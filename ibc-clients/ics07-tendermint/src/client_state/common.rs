@@ -1,12 +1,13 @@
 use ibc_client_tendermint_types::{client_type as tm_client_type, ClientState as ClientStateType};
-use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::context::client_state::{ClientCapabilities, ClientStateCommon};
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::types::error::{ClientError, UpgradeClientError};
 use ibc_core_client::types::Height;
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
-use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc_core_commitment_types::merkle::{apply_prefix_segments, MerkleProof};
+use ibc_core_commitment_types::proto::ics23::commitment_proof::Proof;
 use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_host::types::identifiers::ClientType;
@@ -35,6 +36,10 @@ impl ClientStateCommon for ClientState {
         validate_proof_height(self.inner(), proof_height)
     }
 
+    fn capabilities(&self) -> ClientCapabilities {
+        ClientCapabilities::ALL
+    }
+
     fn verify_upgrade_client(
         &self,
         upgraded_client_state: Any,
@@ -139,6 +144,20 @@ pub fn validate_proof_height(
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateCommon`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            chain_id = %client_state.chain_id,
+            trusted_height = %client_state.latest_height,
+            target_height = tracing::field::Empty,
+        ),
+        ret,
+        err,
+    )
+)]
 pub fn verify_upgrade_client<H: HostFunctionsProvider>(
     client_state: &ClientStateType,
     upgraded_client_state: Any,
@@ -156,6 +175,12 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
     let latest_height = client_state.latest_height;
     let upgraded_tm_client_state_height = upgraded_tm_client_state.latest_height();
 
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record(
+        "target_height",
+        tracing::field::display(upgraded_tm_client_state_height),
+    );
+
     // Make sure the latest height of the current client is not greater then
     // the upgrade height This condition checks both the revision number and
     // the height
@@ -178,7 +203,8 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
     let upgrade_path_prefix = CommitmentPrefix::try_from(upgrade_path[0].clone().into_bytes())
         .map_err(ClientError::InvalidCommitmentProof)?;
 
-    let last_height = latest_height.revision_height();
+    let (upgraded_client_state_path, upgraded_consensus_state_path) =
+        UpgradeClientPath::for_height(latest_height.revision_height());
 
     // Verify the proof of the upgraded client state
     verify_membership::<H>(
@@ -186,7 +212,7 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
         &upgrade_path_prefix,
         &proof_upgrade_client,
         root,
-        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(last_height)),
+        Path::UpgradeClient(upgraded_client_state_path),
         upgraded_client_state.to_vec(),
     )?;
 
@@ -196,13 +222,88 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
         &upgrade_path_prefix,
         &proof_upgrade_consensus_state,
         root,
-        Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(last_height)),
+        Path::UpgradeClient(upgraded_consensus_state_path),
         upgraded_consensus_state.to_vec(),
     )?;
 
     Ok(())
 }
 
+/// Checks whether the counterparty has scheduled an upgrade of the client,
+/// i.e. stores an upgraded client state under the upgrade path of the client
+/// at its latest height, without validating the upgrade itself.
+///
+/// `proof_upgrade_client` must prove either the presence of some upgraded
+/// client state, in which case `true` is returned, or its absence, in which
+/// case `false` is returned. A client without an upgrade path cannot be
+/// upgraded, so no upgrade is considered scheduled for it. Any other upgrade
+/// path must consist of the store prefix and key, and is rejected otherwise.
+pub fn is_upgrade_scheduled<H: HostFunctionsProvider>(
+    client_state: &ClientStateType,
+    proof_upgrade_client: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+) -> Result<bool, ClientError> {
+    let upgrade_path = &client_state.upgrade_path;
+
+    if upgrade_path.is_empty() {
+        return Ok(false);
+    }
+
+    let upgrade_path_prefix = match upgrade_path.first() {
+        Some(prefix) if upgrade_path.len() == 2 => {
+            CommitmentPrefix::try_from(prefix.clone().into_bytes())
+                .map_err(ClientError::InvalidCommitmentProof)?
+        }
+        _ => {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "upgrade path must consist of a store prefix and key, got {upgrade_path:?}"
+                ),
+            })
+        }
+    };
+
+    let path = Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(
+        client_state.latest_height.revision_height(),
+    ));
+
+    // The value proven to be present, if any, is that of the existence proof
+    // of the path itself, i.e. the innermost one.
+    let proven_value = MerkleProof::try_from(proof_upgrade_client)
+        .map_err(ClientError::InvalidCommitmentProof)?
+        .proofs
+        .into_iter()
+        .next()
+        .and_then(|proof| match proof.proof {
+            Some(Proof::Exist(existence_proof)) => Some(existence_proof.value),
+            _ => None,
+        });
+
+    match proven_value {
+        Some(value) => {
+            verify_membership::<H>(
+                &client_state.proof_specs,
+                &upgrade_path_prefix,
+                proof_upgrade_client,
+                root,
+                path,
+                value,
+            )?;
+            Ok(true)
+        }
+        None => {
+            verify_non_membership::<H>(
+                &client_state.proof_specs,
+                &upgrade_path_prefix,
+                proof_upgrade_client,
+                root,
+                path,
+            )?;
+            Ok(false)
+        }
+    }
+}
+
 /// Verify membership of the given value against the client's merkle proof.
 ///
 /// Note that this function is typically implemented as part of the
@@ -216,7 +317,22 @@ pub fn verify_membership<H: HostFunctionsProvider>(
     path: Path,
     value: Vec<u8>,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    verify_membership_with_separator::<H>(proof_specs, prefix, proof, root, path, "/", value)
+}
+
+/// Verify membership of the given value against the client's merkle proof,
+/// where the counterparty stores `path` under the key obtained by joining its
+/// [`key_segments`](Path::key_segments) with `separator`.
+pub fn verify_membership_with_separator<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    separator: &str,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let merkle_path = apply_prefix_segments(prefix, &path.key_segments(), separator);
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
@@ -224,6 +340,58 @@ pub fn verify_membership<H: HostFunctionsProvider>(
         .map_err(ClientError::Ics23Verification)
 }
 
+/// Explains why `proof` fails to prove that the counterparty stores `value`
+/// under `path`, for diagnostic purposes.
+///
+/// A proof with as many sub-proofs as there are `proof_specs`, whose
+/// innermost existence proof commits to a value other than `value`, is
+/// reported as a value mismatch. Any other failure is reported as a likely
+/// proof-spec mismatch, as is e.g. the case for a client whose proof specs
+/// predate a change of those of the counterparty.
+pub fn explain_membership_failure<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> String {
+    let merkle_proof = match MerkleProof::try_from(proof) {
+        Ok(merkle_proof) => merkle_proof,
+        Err(e) => return format!("invalid proof: {e}"),
+    };
+
+    let error = match verify_membership::<H>(proof_specs, prefix, proof, root, path, value.clone())
+    {
+        Ok(()) => return "membership proof verifies".to_string(),
+        Err(e) => e,
+    };
+
+    if merkle_proof.proofs.len() != proof_specs.len() {
+        return format!(
+            "likely proof-spec mismatch: the proof consists of {} proofs, whereas the client has {} proof specs",
+            merkle_proof.proofs.len(),
+            proof_specs.len()
+        );
+    }
+
+    let proven_value = merkle_proof
+        .proofs
+        .into_iter()
+        .next()
+        .and_then(|proof| match proof.proof {
+            Some(Proof::Exist(existence_proof)) => Some(existence_proof.value),
+            _ => None,
+        });
+
+    match proven_value {
+        Some(proven_value) if proven_value != value => {
+            "value mismatch: the proof commits to another value than the expected one".to_string()
+        }
+        _ => format!("likely proof-spec mismatch: {error}"),
+    }
+}
+
 /// Verify that the given value does not belong in the client's merkle proof.
 ///
 /// Note that this function is typically implemented as part of the
@@ -236,7 +404,21 @@ pub fn verify_non_membership<H: HostFunctionsProvider>(
     root: &CommitmentRoot,
     path: Path,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    verify_non_membership_with_separator::<H>(proof_specs, prefix, proof, root, path, "/")
+}
+
+/// Verify that the given value does not belong in the client's merkle proof,
+/// where the counterparty stores `path` under the key obtained by joining its
+/// [`key_segments`](Path::key_segments) with `separator`.
+pub fn verify_non_membership_with_separator<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    separator: &str,
+) -> Result<(), ClientError> {
+    let merkle_path = apply_prefix_segments(prefix, &path.key_segments(), separator);
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
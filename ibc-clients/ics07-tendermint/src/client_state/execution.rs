@@ -1,19 +1,36 @@
+use ibc_client_tendermint_types::error::{AuditError, Error};
 use ibc_client_tendermint_types::{
-    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    client_type as tm_client_type, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, FrozenHeight, Header as TmHeader,
 };
 use ibc_core_client::context::prelude::*;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::proto::ics23::HostFunctionsManager;
 use ibc_core_host::types::identifiers::ClientId;
-use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core_host::types::path::{
+    ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
+};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-
-use super::ClientState;
+use ibc_primitives::Timestamp;
+use tendermint::crypto::default::Sha256;
+use tendermint::Time;
+use tendermint_light_client_verifier::ProdVerifier;
+
+use super::{
+    audit_consensus_history, check_substitute, explain_membership_failure, is_upgrade_scheduled,
+    replay_headers, verify_consensus_state, ClientState,
+};
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::context::TmExecutionContext;
 
 impl<E> ClientStateExecution<E> for ClientState
 where
-    E: ExtClientExecutionContext,
+    E: TmExecutionContext,
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
@@ -80,6 +97,175 @@ where
     }
 }
 
+impl ClientState {
+    /// Checks whether the counterparty has scheduled an upgrade of this
+    /// client, without validating the upgrade. See [`is_upgrade_scheduled`]
+    /// for details.
+    pub fn is_upgrade_scheduled(
+        &self,
+        proof_upgrade_client: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<bool, ClientError> {
+        is_upgrade_scheduled::<HostFunctionsManager>(self.inner(), proof_upgrade_client, root)
+    }
+
+    /// Explains why `proof` fails to prove that the counterparty stores
+    /// `value` under `path`. See [`explain_membership_failure`] for details.
+    pub fn explain_membership_failure(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> String {
+        explain_membership_failure::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            prefix,
+            proof,
+            root,
+            path,
+            value,
+        )
+    }
+
+    /// Computes the states upgrading this client results in, without
+    /// storing them. See [`simulate_upgrade`] for details.
+    pub fn simulate_upgrade(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<(ClientState, TmConsensusState), ClientError> {
+        let (client_state, consensus_state) = simulate_upgrade(
+            self.inner(),
+            upgraded_client_state,
+            upgraded_consensus_state,
+        )?;
+
+        Ok((client_state.into(), consensus_state.into()))
+    }
+
+    /// Migrates the client identified by `subject_client_id` to the state of
+    /// the substitute client. See [`check_substitute_and_update_state`] for
+    /// details.
+    pub fn check_substitute_and_update_state<E>(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<(), ClientError>
+    where
+        E: ExtClientExecutionContext,
+        E::ClientStateRef: From<ClientStateType>,
+        ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    {
+        check_substitute_and_update_state(
+            self.inner(),
+            ctx,
+            subject_client_id,
+            substitute_client_id,
+        )
+    }
+
+    /// Verifies a sequence of headers offline against the `genesis` consensus
+    /// state, using the [`ProdVerifier`]. See [`replay_headers`] for details.
+    pub fn replay_headers(
+        &self,
+        genesis: ConsensusStateType,
+        genesis_height: Height,
+        headers: &[TmHeader],
+        now: Timestamp,
+    ) -> Result<Vec<Height>, ClientError> {
+        replay_headers::<Sha256>(
+            self.inner(),
+            genesis,
+            genesis_height,
+            headers,
+            now,
+            &ProdVerifier::default(),
+        )
+    }
+
+    /// Re-records the processed time and height of the consensus state at the
+    /// latest height of the client identified by `client_id`. See
+    /// [`touch_latest`] for details.
+    pub fn touch_latest<E>(&self, ctx: &mut E, client_id: &ClientId) -> Result<(), ClientError>
+    where
+        E: ExtClientExecutionContext,
+    {
+        touch_latest(self.inner(), ctx, client_id)
+    }
+
+    /// Counts the consensus states of the client identified by `client_id`
+    /// that the next update would prune if the host timestamp were `now`.
+    /// See [`count_prunable_consensus_states`] for details.
+    pub fn count_prunable<E>(
+        &self,
+        ctx: &E,
+        client_id: &ClientId,
+        now: Timestamp,
+    ) -> Result<usize, ClientError>
+    where
+        E: ExtClientExecutionContext,
+        ConsensusStateType: Convertible<E::ConsensusStateRef>,
+        <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        count_prunable_consensus_states(self.inner(), ctx, client_id, now)
+    }
+
+    /// Audits the consensus states stored for the client identified by
+    /// `client_id`. See [`audit_consensus_history`] for details.
+    pub fn audit_consensus_history<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+    ) -> Result<(), AuditError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        audit_consensus_history(self.inner(), ctx, client_id)
+    }
+
+    /// Returns the store paths that [`initialise`] writes for the client
+    /// identified by `client_id`, e.g. to pre-authorize or meter them.
+    pub fn write_paths_on_initialise(&self, client_id: &ClientId) -> Vec<Path> {
+        write_paths_at_height(client_id, self.0.latest_height)
+    }
+
+    /// Returns the store paths that [`update_state`] writes when updating the
+    /// client identified by `client_id` with a header at `header_height`.
+    ///
+    /// These exclude the paths deleted along the way by pruning expired
+    /// consensus states. No path is written if a consensus state is already
+    /// installed at `header_height`.
+    pub fn write_paths_on_update(&self, client_id: &ClientId, header_height: Height) -> Vec<Path> {
+        write_paths_at_height(client_id, header_height)
+    }
+}
+
+/// Paths of the client state, and of the consensus state and its update
+/// metadata at `height`.
+fn write_paths_at_height(client_id: &ClientId, height: Height) -> Vec<Path> {
+    vec![
+        ClientStatePath::new(client_id.clone()).into(),
+        height.consensus_state_path(client_id).into(),
+        ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        )
+        .into(),
+        ClientUpdateHeightPath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        )
+        .into(),
+    ]
+}
+
 /// Seed the host store with initial client and consensus states.
 ///
 /// Note that this function is typically implemented as part of the
@@ -96,6 +282,19 @@ where
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
 {
+    if ctx.requires_typed_client_ids() && client_id.client_type() != Some(tm_client_type()) {
+        return Err(Error::MismatchClientIdType {
+            client_id: client_id.clone(),
+            client_type: tm_client_type(),
+        }
+        .into());
+    }
+
+    // Re-validate both states before any store call, so that a malformed
+    // create-client message can never install an inconsistent client.
+    client_state.validate()?;
+    verify_consensus_state(consensus_state.clone())?;
+
     let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
     let host_height = ExtClientValidationContext::host_height(ctx)?;
 
@@ -106,11 +305,7 @@ where
         client_state.clone().into(),
     )?;
     ctx.store_consensus_state(
-        ClientConsensusStatePath::new(
-            client_id.clone(),
-            client_state.latest_height.revision_number(),
-            client_state.latest_height.revision_height(),
-        ),
+        client_state.latest_height.consensus_state_path(client_id),
         tm_consensus_state.into(),
     )?;
 
@@ -143,38 +338,64 @@ where
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
 {
     let header = TmHeader::try_from(header)?;
-    let header_height = header.height();
+    let (header_height, header_timestamp): (Height, Timestamp) = (&header).into();
+
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    // A consensus state from beyond the clock drift of the host would look
+    // fresh for longer than the trusting period. This is checked before
+    // pruning, so that a rejected header leaves the store untouched.
+    if host_timestamp.is_set() {
+        let max_timestamp =
+            (host_timestamp + client_state.max_clock_drift).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        if header_timestamp > max_timestamp {
+            return Err(Error::ConsensusStateTimestampTooFarInFuture {
+                timestamp: header_timestamp,
+                max: max_timestamp,
+            }
+            .into());
+        }
+    }
 
     prune_oldest_consensus_state(client_state, ctx, client_id)?;
 
+    let new_consensus_state = ConsensusStateType::from(header.clone());
+
     let maybe_existing_consensus_state = {
-        let path_at_header_height = ClientConsensusStatePath::new(
-            client_id.clone(),
-            header_height.revision_number(),
-            header_height.revision_height(),
-        );
+        let path_at_header_height = header_height.consensus_state_path(client_id);
 
         ctx.consensus_state(&path_at_header_height).ok()
     };
 
-    if maybe_existing_consensus_state.is_some() {
+    if let Some(existing_consensus_state) = maybe_existing_consensus_state {
+        let existing_consensus_state: ConsensusStateType =
+            existing_consensus_state.try_into().map_err(Into::into)?;
+
         // if we already had the header installed by a previous relayer
-        // then this is a no-op.
-        //
-        // Do nothing.
+        // then this is a no-op, whereas a conflicting consensus state is
+        // evidence of misbehaviour, which must never be overwritten.
+        if !existing_consensus_state.content_eq(&new_consensus_state) {
+            return Err(Error::ConflictingConsensusState {
+                client_id: client_id.clone(),
+                height: header_height,
+            }
+            .into());
+        }
     } else {
-        let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
-        let host_height = ExtClientValidationContext::host_height(ctx)?;
+        let mut new_client_state = client_state.clone().with_header(header)?;
 
-        let new_consensus_state = ConsensusStateType::from(header.clone());
-        let new_client_state = client_state.clone().with_header(header)?;
+        // A header accepted for a frozen client, which allows updates after
+        // misbehaviour, revives it.
+        if new_client_state.allow_update.after_misbehaviour {
+            new_client_state.frozen_height = FrozenHeight::not_frozen();
+        }
 
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                header_height.revision_number(),
-                header_height.revision_height(),
-            ),
+            header_height.consensus_state_path(client_id),
             new_consensus_state.into(),
         )?;
         ctx.store_client_state(
@@ -208,12 +429,10 @@ where
     E: ExtClientExecutionContext,
     E::ClientStateRef: From<ClientStateType>,
 {
-    // NOTE: frozen height is  set to `Height {revision_height: 0,
-    // revision_number: 1}` and it is the same for all misbehaviour. This
-    // aligns with the
-    // [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
-    // implementation.
-    let frozen_client_state = client_state.clone().with_frozen_height(Height::min(0));
+    let frozen_client_state = ClientStateType {
+        frozen_height: FrozenHeight::misbehaviour_sentinel(),
+        ..client_state.clone()
+    };
 
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
@@ -241,9 +460,61 @@ where
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
 {
+    let (new_client_state, new_consensus_state) = simulate_upgrade(
+        client_state,
+        upgraded_client_state,
+        upgraded_consensus_state,
+    )?;
+
+    let latest_height = new_client_state.latest_height;
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+    ctx.store_consensus_state(
+        latest_height.consensus_state_path(client_id),
+        new_consensus_state.into(),
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(latest_height)
+}
+
+/// Computes the client and consensus states that upgrading the client to
+/// the given upgraded states results in, as stored by [`update_on_upgrade`],
+/// without touching any store. This lets e.g. governance reviewers preview
+/// the client after an upgrade.
+pub fn simulate_upgrade(
+    client_state: &ClientStateType,
+    upgraded_client_state: Any,
+    upgraded_consensus_state: Any,
+) -> Result<(ClientStateType, ConsensusStateType), ClientError> {
     let mut upgraded_tm_client_state = ClientState::try_from(upgraded_client_state)?;
     let upgraded_tm_cons_state: ConsensusStateType = upgraded_consensus_state.try_into()?;
 
+    // The sentinel consensus state below inherits these fields, so a
+    // degenerate upgraded consensus state must not make it to the store.
+    if upgraded_tm_cons_state.timestamp == Time::unix_epoch() {
+        return Err(Error::InvalidUpgradedConsensusState {
+            reason: "timestamp must not be zero".to_string(),
+        }
+        .into());
+    }
+    if upgraded_tm_cons_state.next_validators_hash().is_empty() {
+        return Err(Error::InvalidUpgradedConsensusState {
+            reason: "next validators hash must not be empty".to_string(),
+        }
+        .into());
+    }
+
     upgraded_tm_client_state.0.zero_custom_fields();
 
     // Construct new client state and consensus state relayer chosen client
@@ -278,26 +549,109 @@ where
     let sentinel_root = b"sentinel_root".to_vec();
     let new_consensus_state = ConsensusStateType::new(
         sentinel_root.into(),
-        upgraded_tm_cons_state.timestamp(),
-        upgraded_tm_cons_state.next_validators_hash,
+        upgraded_tm_cons_state.timestamp,
+        upgraded_tm_cons_state.next_validators_hash(),
     );
 
-    let latest_height = new_client_state.latest_height;
+    Ok((new_client_state, new_consensus_state))
+}
+
+/// Checks that the substitute client matches the subject client and, if so,
+/// updates the subject client with the substitute's latest state.
+///
+/// This is the counterpart of ibc-go's `CheckSubstituteAndUpdateState`, used to
+/// migrate a frozen or expired client under governance. It combines
+/// [`check_substitute`](super::check_substitute), which validates that all
+/// chain-chosen parameters of the two clients match, with
+/// [`update_on_recovery`], which copies the substitute's consensus state at
+/// its latest height, its latest height, trusting period and chain ID, and
+/// unfreezes the subject client. The substitute's consensus states at the
+/// other heights are then copied over as well, so that the subject client can
+/// verify proofs at any height the substitute could. All the copied consensus
+/// states are recorded as processed at the current host time and height.
+///
+/// Note that, unlike the `recover_client` handler, this function does not check
+/// the status or the relative heights of the subject and substitute clients.
+pub fn check_substitute_and_update_state<E>(
+    subject_client_state: &ClientStateType,
+    ctx: &mut E,
+    subject_client_id: &ClientId,
+    substitute_client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let substitute_client_state = ctx.client_state(substitute_client_id)?;
+    let substitute_latest_height = substitute_client_state.latest_height();
+    let substitute_consensus_state: Any = ctx
+        .consensus_state(&substitute_latest_height.consensus_state_path(substitute_client_id))?
+        .into();
+    let substitute_client_state: Any = substitute_client_state.into();
+
+    check_substitute::<E>(subject_client_state, substitute_client_state.clone())?;
+
+    update_on_recovery(
+        subject_client_state.clone(),
+        ctx,
+        subject_client_id,
+        substitute_client_state,
+        substitute_consensus_state,
+    )?;
+
+    let host_timestamp = E::host_timestamp(ctx)?;
+    let host_height = E::host_height(ctx)?;
+
+    for height in ctx.consensus_state_heights(substitute_client_id)? {
+        if height == substitute_latest_height {
+            continue;
+        }
+
+        let consensus_state =
+            ctx.consensus_state(&height.consensus_state_path(substitute_client_id))?;
+
+        ctx.store_consensus_state(
+            height.consensus_state_path(subject_client_id),
+            consensus_state,
+        )?;
+
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            height,
+            host_timestamp,
+            host_height,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Re-writes the update metadata of the consensus state at the client's
+/// latest height with the current host time and height, leaving the client
+/// and consensus states untouched.
+///
+/// This is meant for recovery scenarios, e.g. after a host migration, where a
+/// fresh processed time and height must be recorded without submitting a new
+/// header. It resets the delay period of connections using the client, but
+/// does not affect its expiry, which is keyed on the timestamp of the
+/// consensus state itself.
+pub fn touch_latest<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+{
+    let latest_height = client_state.latest_height;
+
+    // Only touch a consensus state which is actually installed.
+    ctx.consensus_state(&latest_height.consensus_state_path(client_id))?;
+
     let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
     let host_height = ExtClientValidationContext::host_height(ctx)?;
 
-    ctx.store_client_state(
-        ClientStatePath::new(client_id.clone()),
-        new_client_state.into(),
-    )?;
-    ctx.store_consensus_state(
-        ClientConsensusStatePath::new(
-            client_id.clone(),
-            latest_height.revision_number(),
-            latest_height.revision_height(),
-        ),
-        new_consensus_state.into(),
-    )?;
     ctx.store_update_meta(
         client_id.clone(),
         latest_height,
@@ -305,62 +659,147 @@ where
         host_height,
     )?;
 
-    Ok(latest_height)
+    Ok(())
 }
 
 /// Removes consensus states from the client store whose timestamps
 /// are less than or equal to the host timestamp. This ensures that
 /// the client store does not amass a buildup of stale consensus states.
+///
+/// If the host provides a [`ConsensusStateCachePolicy`], the policy decides
+/// instead which consensus states are removed.
+///
+/// Returns the heights of the pruned consensus states, each of which is
+/// also reported through [`ClientExecutionContext::on_consensus_state_pruned`].
 pub fn prune_oldest_consensus_state<E>(
     client_state: &ClientStateType,
     ctx: &mut E,
     client_id: &ClientId,
-) -> Result<(), ClientError>
+) -> Result<Vec<Height>, ClientError>
 where
     E: ClientExecutionContext + ExtClientValidationContext,
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
 {
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let pruned_heights = prunable_heights(client_state, ctx, client_id, host_timestamp)?;
+
+    for height in pruned_heights.iter().copied() {
+        ctx.delete_consensus_state(height.consensus_state_path(client_id))?;
+        ctx.delete_update_meta(client_id.clone(), height)?;
+        ctx.on_consensus_state_pruned(client_id, height)?;
+    }
+
+    Ok(pruned_heights)
+}
+
+/// Counts the consensus states of the client identified by `client_id` that
+/// [`prune_oldest_consensus_state`] would remove if the host timestamp were
+/// `now`, without removing any.
+pub fn count_prunable_consensus_states<E>(
+    client_state: &ClientStateType,
+    ctx: &E,
+    client_id: &ClientId,
+    now: Timestamp,
+) -> Result<usize, ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    Ok(prunable_heights(client_state, ctx, client_id, now)?.len())
+}
+
+/// Returns the heights, in increasing order, of the consensus states to be
+/// pruned as of the host timestamp `now`.
+fn prunable_heights<E>(
+    client_state: &ClientStateType,
+    ctx: &E,
+    client_id: &ClientId,
+    now: Timestamp,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    // A policy may evict consensus states in any order, whereas without one,
+    // consensus states expire in height order.
+    let has_policy = ctx.consensus_state_cache_policy().is_some();
+
+    // Without a policy, nothing is to be pruned unless the oldest consensus
+    // state is, which saves listing all of them.
+    if !has_policy {
+        let Some(oldest_height) = ctx.oldest_consensus_state_height(client_id)? else {
+            return Ok(Vec::new());
+        };
+
+        if !should_evict_consensus_state(client_state, ctx, client_id, oldest_height, now)? {
+            return Ok(Vec::new());
+        }
+    }
+
     let mut heights = ctx.consensus_state_heights(client_id)?;
+    let mut prunable_heights = Vec::new();
 
     heights.sort();
 
     for height in heights {
-        let client_consensus_state_path = ClientConsensusStatePath::new(
-            client_id.clone(),
-            height.revision_number(),
-            height.revision_height(),
-        );
-        let consensus_state = ctx.consensus_state(&client_consensus_state_path)?;
-        let tm_consensus_state: ConsensusStateType =
-            consensus_state.try_into().map_err(Into::into)?;
-
-        let host_timestamp =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::Other {
-                    description: String::from("host timestamp is not a valid TM timestamp"),
-                })?;
-
-        let tm_consensus_state_timestamp = tm_consensus_state.timestamp();
-        let tm_consensus_state_expiry = (tm_consensus_state_timestamp
-            + client_state.trusting_period)
-            .map_err(|_| ClientError::Other {
-                description: String::from(
-                    "Timestamp overflow error occurred while attempting to parse TmConsensusState",
-                ),
-            })?;
-
-        if tm_consensus_state_expiry > host_timestamp {
+        if !should_evict_consensus_state(client_state, ctx, client_id, height, now)? {
+            if has_policy {
+                continue;
+            }
+            // So if this one hasn't expired yet, neither has any later one.
             break;
         }
 
-        ctx.delete_consensus_state(client_consensus_state_path)?;
-        ctx.delete_update_meta(client_id.clone(), height)?;
+        prunable_heights.push(height);
     }
 
-    Ok(())
+    Ok(prunable_heights)
+}
+
+/// Checks whether the consensus state at `height` should be pruned, i.e.
+/// whether it has outlived the trusting period relative to the host
+/// timestamp `now`, unless the host's [`ConsensusStateCachePolicy`] decides
+/// otherwise.
+fn should_evict_consensus_state<E>(
+    client_state: &ClientStateType,
+    ctx: &E,
+    client_id: &ClientId,
+    height: Height,
+    now: Timestamp,
+) -> Result<bool, ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let consensus_state = ctx.consensus_state(&height.consensus_state_path(client_id))?;
+    let tm_consensus_state: ConsensusStateType = consensus_state.try_into().map_err(Into::into)?;
+
+    let host_timestamp = now.into_tm_time().ok_or_else(|| ClientError::Other {
+        description: String::from("host timestamp is not a valid TM timestamp"),
+    })?;
+
+    let tm_consensus_state_timestamp = tm_consensus_state.timestamp;
+    let tm_consensus_state_expiry = (tm_consensus_state_timestamp + client_state.trusting_period)
+        .map_err(|_| ClientError::Other {
+        description: String::from(
+            "Timestamp overflow error occurred while attempting to parse TmConsensusState",
+        ),
+    })?;
+
+    let meta = ConsensusStateMeta {
+        timestamp: tm_consensus_state.timestamp(),
+        is_expired: tm_consensus_state_expiry <= host_timestamp,
+    };
+
+    Ok(match ctx.consensus_state_cache_policy() {
+        Some(policy) => policy.should_evict(client_id, height, &meta),
+        None => meta.is_expired,
+    })
 }
 
 /// Update the `client_state`'s ID, trusting period, latest height, processed height,
@@ -398,7 +837,7 @@ where
         chain_id,
         trusting_period,
         latest_height,
-        frozen_height: None,
+        frozen_height: FrozenHeight::not_frozen(),
         ..subject_client_state
     };
 
@@ -408,11 +847,9 @@ where
     let tm_consensus_state: ConsensusStateType = substitute_consensus_state.try_into()?;
 
     ctx.store_consensus_state(
-        ClientConsensusStatePath::new(
-            subject_client_id.clone(),
-            new_client_state.latest_height.revision_number(),
-            new_client_state.latest_height.revision_height(),
-        ),
+        new_client_state
+            .latest_height
+            .consensus_state_path(subject_client_id),
         tm_consensus_state.into(),
     )?;
 
@@ -2,10 +2,9 @@ use ibc_client_tendermint_types::error::{Error, IntoResult};
 use ibc_client_tendermint_types::{
     ConsensusState as ConsensusStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
 };
-use ibc_core_client::context::{Convertible, ExtClientValidationContext};
+use ibc_core_client::context::Convertible;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
-use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 use tendermint::crypto::Sha256;
@@ -14,10 +13,27 @@ use tendermint::{Hash, Time};
 use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::Verifier;
 
+use crate::context::TmValidationContext;
 use crate::types::Header;
 
 /// Determines whether or not two conflicting headers at the same height would
 /// have convinced the light client.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            client_id = %client_id,
+            header1.trusted_height = %misbehaviour.header1().trusted_height,
+            header1.target_height = %misbehaviour.header1().height(),
+            header2.trusted_height = %misbehaviour.header2().trusted_height,
+            header2.target_height = %misbehaviour.header2().height(),
+        ),
+        ret,
+        err,
+    )
+)]
 pub fn verify_misbehaviour<V, H>(
     ctx: &V,
     misbehaviour: &TmMisbehaviour,
@@ -27,20 +43,26 @@ pub fn verify_misbehaviour<V, H>(
     verifier: &impl Verifier,
 ) -> Result<(), ClientError>
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256 + Default,
 {
+    // As for updates, oversized validator sets are rejected before anything
+    // gets hashed or verified.
+    let max_header_validators = ctx.max_header_validators();
+    misbehaviour
+        .header1()
+        .validate_validator_set_size(max_header_validators)?;
+    misbehaviour
+        .header2()
+        .validate_validator_set_size(max_header_validators)?;
+
     misbehaviour.validate_basic::<H>()?;
 
     let header_1 = misbehaviour.header1();
     let trusted_consensus_state_1: ConsensusStateType = {
-        let consensus_state_path = ClientConsensusStatePath::new(
-            client_id.clone(),
-            header_1.trusted_height.revision_number(),
-            header_1.trusted_height.revision_height(),
-        );
+        let consensus_state_path = header_1.trusted_height.consensus_state_path(client_id);
         let consensus_state = ctx.consensus_state(&consensus_state_path)?;
 
         consensus_state.try_into().map_err(Into::into)?
@@ -48,11 +70,7 @@ where
 
     let header_2 = misbehaviour.header2();
     let trusted_consensus_state_2: ConsensusStateType = {
-        let consensus_state_path = ClientConsensusStatePath::new(
-            client_id.clone(),
-            header_2.trusted_height.revision_number(),
-            header_2.trusted_height.revision_height(),
-        );
+        let consensus_state_path = header_2.trusted_height.consensus_state_path(client_id);
         let consensus_state = ctx.consensus_state(&consensus_state_path)?;
 
         consensus_state.try_into().map_err(Into::into)?
@@ -64,8 +82,8 @@ where
         header_1,
         chain_id,
         options,
-        trusted_consensus_state_1.timestamp(),
-        trusted_consensus_state_1.next_validators_hash,
+        trusted_consensus_state_1.timestamp,
+        trusted_consensus_state_1.next_validators_hash(),
         current_timestamp,
         verifier,
     )?;
@@ -73,8 +91,8 @@ where
         header_2,
         chain_id,
         options,
-        trusted_consensus_state_2.timestamp(),
-        trusted_consensus_state_2.next_validators_hash,
+        trusted_consensus_state_2.timestamp,
+        trusted_consensus_state_2.next_validators_hash(),
         current_timestamp,
         verifier,
     )
@@ -1,17 +1,53 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+};
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
-use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::Time;
 use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 use tendermint_light_client_verifier::Verifier;
 
+use crate::context::{TmValidationContext, DEFAULT_MAX_HEADER_VALIDATORS};
+
+/// Verifies `header` against the consensus state stored at its trusted height.
+///
+/// The header must be from the chain identified by `chain_id`.
+///
+/// The time of the header is bounded on both sides. Ahead of the host time, it
+/// may drift by at most the `max_clock_drift` of the client. Backwards, it must
+/// be strictly later than the time of the trusted consensus state (BFT time
+/// monotonicity), so that no backward drift whatsoever is tolerated relative to
+/// it, failing with [`ClientError::HeaderTimestampTooOld`] otherwise. The
+/// neighbouring consensus states of the header are further checked for time
+/// monotonicity by [`check_for_misbehaviour_on_update`].
+///
+/// The trusted next validator set the host has locally, if any, as returned by
+/// [`TmValidationContext::trusted_next_validator_set`], takes precedence over
+/// the relayer-supplied one. Either way, it is checked against the next
+/// validators hash of the trusted consensus state.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            client_id = %client_id,
+            trusted_height = %header.trusted_height,
+            target_height = %header.height(),
+        ),
+        ret,
+        err,
+    )
+)]
 pub fn verify_header<V, H>(
     ctx: &V,
     header: &TmHeader,
@@ -21,11 +57,81 @@ pub fn verify_header<V, H>(
     verifier: &impl Verifier,
 ) -> Result<(), ClientError>
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256 + Default,
 {
+    check_header::<H>(header, chain_id, ctx.max_header_validators())?;
+
+    // Hosts may disallow skipping verification, in which case the header must
+    // immediately follow its trusted height.
+    if ctx.requires_adjacent_updates(client_id) {
+        let header_height = header.height();
+        if header.trusted_height.increment() != header_height {
+            return Err(ClientError::HeaderVerificationFailure {
+                reason: format!(
+                    "header at height {header_height} is not adjacent to trusted height {}",
+                    header.trusted_height
+                ),
+            });
+        }
+    }
+
+    let trusted_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&header.trusted_height.consensus_state_path(client_id))?
+        .try_into()
+        .map_err(Into::into)?;
+
+    let now = ctx
+        .host_timestamp()?
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    let local_trusted_next_validator_set =
+        ctx.trusted_next_validator_set(client_id, header.trusted_height);
+
+    verify_header_against_trusted_state::<H>(
+        header,
+        local_trusted_next_validator_set
+            .as_ref()
+            .unwrap_or(&header.trusted_next_validator_set),
+        &trusted_consensus_state,
+        chain_id,
+        options,
+        verifier,
+        now,
+    )
+}
+
+/// Runs the checks of `header` which require no trusted state, ahead of its
+/// verification against one, for a client of the chain `chain_id` accepting at
+/// most `max_header_validators` validators per header.
+fn check_header<H>(
+    header: &TmHeader,
+    chain_id: &ChainId,
+    max_header_validators: usize,
+) -> Result<(), ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    // A header from another chain may well be valid, so it must be rejected
+    // before anything else.
+    let header_chain_id = &header.signed_header.header.chain_id;
+    if header_chain_id.as_str() != chain_id.as_str() {
+        return Err(ClientError::HeaderVerificationFailure {
+            reason: format!(
+                "header chain id `{header_chain_id}` does not match client chain id `{chain_id}`"
+            ),
+        });
+    }
+
+    // Oversized validator sets are rejected before anything gets hashed or
+    // verified.
+    header.validate_validator_set_size(max_header_validators)?;
+
     // Checks that the header fields are valid.
     header.validate_basic::<H>()?;
 
@@ -33,70 +139,152 @@ where
     // to have the same revision number. We ensure this here.
     header.verify_chain_id_version_matches_height(chain_id)?;
 
-    // Delegate to tendermint-light-client, which contains the required checks
-    // of the new header against the trusted consensus state.
+    Ok(())
+}
+
+/// Delegates to tendermint-light-client, which contains the required checks
+/// of the new header against the trusted consensus state, whose next
+/// validators are `trusted_next_validator_set`.
+fn verify_header_against_trusted_state<H>(
+    header: &TmHeader,
+    trusted_next_validator_set: &ValidatorSet,
+    trusted_consensus_state: &ConsensusStateType,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+    now: Time,
+) -> Result<(), ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    if trusted_next_validator_set.hash_with::<H>() != trusted_consensus_state.next_validators_hash()
     {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: ConsensusStateType = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(Into::into)?;
-
-            header.check_trusted_next_validator_set::<H>(
-                &trusted_consensus_state.next_validators_hash,
+        return Err(ClientError::HeaderVerificationFailure {
+            reason: "header trusted next validator set hash does not match hash stored on chain"
+                .to_string(),
+        });
+    }
+
+    // Surface monotonicity violations on their own, so that relayers can
+    // tell stale headers apart from other verification failures.
+    let header_time: Timestamp = header.signed_header.header.time.into();
+    let trusted_time = trusted_consensus_state.timestamp();
+    if header_time <= trusted_time {
+        return Err(ClientError::HeaderTimestampTooOld {
+            header_time,
+            trusted_time,
+        });
+    }
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &chain_id
+            .as_str()
+            .try_into()
+            .map_err(|e| ClientError::Other {
+                description: format!("failed to parse chain id: {}", e),
+            })?,
+        header_time: trusted_consensus_state.timestamp,
+        height: header
+            .trusted_height
+            .revision_height()
+            .try_into()
+            .map_err(|_| ClientError::ClientSpecific {
+                description: Error::InvalidHeaderHeight {
+                    height: header.trusted_height.revision_height(),
+                }
+                .to_string(),
+            })?,
+        next_validators: trusted_next_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash(),
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // NB: This will skip the
+        // VerificationPredicates::next_validators_match check for the
+        // untrusted state.
+        next_validators: None,
+    };
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verify_update_header(untrusted_state, trusted_state, options, now)
+        .into_result()?;
+
+    Ok(())
+}
+
+/// Verifies a sequence of headers offline, i.e. without any host context,
+/// starting from the trusted `genesis` consensus state at `genesis_height`.
+///
+/// Each header is verified as of `now` against the consensus state at its
+/// trusted height, which must be either `genesis_height` or the height of a
+/// header accepted earlier in the sequence. As by [`verify_header`], each
+/// header must be from the client chain and have at most
+/// [`DEFAULT_MAX_HEADER_VALIDATORS`] validators. Returns the heights of all
+/// headers, in order, or an error identifying the first header that fails
+/// verification.
+pub fn replay_headers<H>(
+    client_state: &ClientStateType,
+    genesis: ConsensusStateType,
+    genesis_height: Height,
+    headers: &[TmHeader],
+    now: Timestamp,
+    verifier: &impl Verifier,
+) -> Result<Vec<Height>, ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    let options = client_state.as_light_client_options()?;
+    let now = now
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "replay timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    let mut trusted_consensus_states = BTreeMap::from([(genesis_height, genesis)]);
+    let mut accepted_heights = Vec::with_capacity(headers.len());
+
+    for (index, header) in headers.iter().enumerate() {
+        let header_height = header.height();
+
+        let verify = || -> Result<(), ClientError> {
+            check_header::<H>(
+                header,
+                client_state.chain_id(),
+                DEFAULT_MAX_HEADER_VALIDATORS,
             )?;
 
-            TrustedBlockState {
-                chain_id: &chain_id
-                    .as_str()
-                    .try_into()
-                    .map_err(|e| ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash,
-            }
-        };
+            let trusted_consensus_state = trusted_consensus_states
+                .get(&header.trusted_height)
+                .ok_or_else(|| ClientError::Other {
+                    description: format!(
+                        "no trusted consensus state at height {}",
+                        header.trusted_height
+                    ),
+                })?;
 
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
+            verify_header_against_trusted_state::<H>(
+                header,
+                &header.trusted_next_validator_set,
+                trusted_consensus_state,
+                client_state.chain_id(),
+                &options,
+                verifier,
+                now,
+            )
         };
 
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
+        verify().map_err(|e| ClientError::HeaderVerificationFailure {
+            reason: format!("header #{index} at height {header_height}: {e}"),
+        })?;
 
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verify_update_header(untrusted_state, trusted_state, options, now)
-            .into_result()?;
+        trusted_consensus_states.insert(header_height, ConsensusStateType::from(header.clone()));
+        accepted_heights.push(header_height);
     }
 
-    Ok(())
+    Ok(accepted_heights)
 }
 
 /// Checks for misbehaviour upon receiving a new consensus state as part
@@ -112,12 +300,10 @@ where
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
 {
+    let (header_height, header_timestamp): (Height, Timestamp) = (&header).into();
+
     let maybe_existing_consensus_state = {
-        let path_at_header_height = ClientConsensusStatePath::new(
-            client_id.clone(),
-            header.height().revision_number(),
-            header.height().revision_height(),
-        );
+        let path_at_header_height = header_height.consensus_state_path(client_id);
 
         ctx.consensus_state(&path_at_header_height).ok()
     };
@@ -129,22 +315,23 @@ where
         let header_consensus_state = ConsensusStateType::from(header);
 
         // There is evidence of misbehaviour if the stored consensus state
-        // is different from the new one we received.
-        Ok(existing_consensus_state != header_consensus_state)
+        // is different from the new one we received: a differing root,
+        // timestamp or next validators hash all indicate a fork.
+        Ok(!existing_consensus_state.content_eq(&header_consensus_state))
     } else {
         // If no header was previously installed, we ensure the monotonicity of timestamps.
 
         // 1. for all headers, the new header needs to have a larger timestamp than
         //    the “previous header”
         {
-            let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header.height())?;
+            let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header_height)?;
 
             if let Some(prev_cs) = maybe_prev_cs {
                 // New header timestamp cannot occur *before* the
                 // previous consensus state's height
                 let prev_cs: ConsensusStateType = prev_cs.try_into().map_err(Into::into)?;
 
-                if header.signed_header.header().time <= prev_cs.timestamp() {
+                if header_timestamp <= prev_cs.timestamp() {
                     return Ok(true);
                 }
             }
@@ -152,15 +339,15 @@ where
 
         // 2. if a header comes in and is not the “last” header, then we also ensure
         //    that its timestamp is less than the “next header”
-        if &header.height() < client_latest_height {
-            let maybe_next_cs = ctx.next_consensus_state(client_id, &header.height())?;
+        if &header_height < client_latest_height {
+            let maybe_next_cs = ctx.next_consensus_state(client_id, &header_height)?;
 
             if let Some(next_cs) = maybe_next_cs {
                 // New (untrusted) header timestamp cannot occur *after* next
                 // consensus state's height
                 let next_cs: ConsensusStateType = next_cs.try_into().map_err(Into::into)?;
 
-                if header.signed_header.header().time >= next_cs.timestamp() {
+                if header_timestamp >= next_cs.timestamp() {
                     return Ok(true);
                 }
             }
@@ -1,3 +1,4 @@
+use ibc_client_tendermint_types::error::{AuditError, Error};
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
     Misbehaviour as TmMisbehaviour, TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
@@ -5,11 +6,11 @@ use ibc_client_tendermint_types::{
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
-use ibc_core_client::types::Status;
+use ibc_core_client::types::{Height, Status};
 use ibc_core_host::types::identifiers::ClientId;
-use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
 use tendermint::crypto::default::Sha256;
 use tendermint::crypto::Sha256 as Sha256Trait;
 use tendermint::merkle::MerkleHash;
@@ -17,10 +18,11 @@ use tendermint_light_client_verifier::{ProdVerifier, Verifier};
 
 use super::{check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update, ClientState};
 use crate::client_state::{verify_header, verify_misbehaviour};
+use crate::context::TmValidationContext;
 
 impl<V> ClientStateValidation<V> for ClientState
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
 {
@@ -28,7 +30,8 @@ where
     /// standalone `verify_client_message` function. This is to make it as
     /// simple as possible for those who merely need the default
     /// [`ProdVerifier`] behaviour, as well as those who require custom
-    /// verification logic.
+    /// verification logic. The [`ProdVerifier`] is stateless and constructed
+    /// only here, on demand, so decoding client states never builds one.
     ///
     /// In a situation where the Tendermint [`ProdVerifier`] doesn't provide the
     /// desired outcome, users should define a custom verifier struct and then
@@ -71,6 +74,10 @@ where
         status(self.inner(), ctx, client_id)
     }
 
+    fn check_update_allowed(&self, ctx: &V, client_id: &ClientId) -> Result<(), ClientError> {
+        check_update_allowed(self.inner(), ctx, client_id)
+    }
+
     fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
         check_substitute::<V>(self.inner(), substitute_client_state)
     }
@@ -93,20 +100,71 @@ pub fn verify_client_message<V, H>(
     verifier: &impl Verifier,
 ) -> Result<(), ClientError>
 where
-    V: ExtClientValidationContext,
+    V: TmValidationContext,
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256Trait + Default,
 {
+    // A frozen client can no longer be updated, so verifying messages for it
+    // is wasted work. The exception is a header reviving a client that allows
+    // updates after misbehaviour.
+    if client_state.is_frozen()
+        && !(client_state.allow_update.after_misbehaviour
+            && client_message.type_url == TENDERMINT_HEADER_TYPE_URL)
+    {
+        return Err(ClientError::ClientFrozen {
+            description: format!("client {client_id} is frozen"),
+        });
+    }
+
     match client_message.type_url.as_str() {
         TENDERMINT_HEADER_TYPE_URL => {
             let header = TmHeader::try_from(client_message)?;
+
+            let mut options = client_state.as_light_client_options()?;
+
+            // An expired client that allows updates after expiry is revived by
+            // a valid header. Its trusted consensus state is then necessarily
+            // outside of the trusting period, so for this verification only,
+            // the trusting period is extended to cover the time elapsed since
+            // the trusted consensus state. All other checks still apply.
+            //
+            // The extended period never reaches the unbonding period: past
+            // it, the trusted validators may have unbonded, and trusting them
+            // would open the client to long-range attacks.
+            if client_state.allow_update.after_expiry
+                && status(client_state, ctx, client_id)?.is_expired()
+            {
+                let trusted_consensus_state: ConsensusStateType = ctx
+                    .consensus_state(&header.trusted_height.consensus_state_path(client_id))?
+                    .try_into()
+                    .map_err(Into::into)?;
+
+                let elapsed_since_trusted_consensus_state = ctx
+                    .host_timestamp()?
+                    .duration_since(&trusted_consensus_state.timestamp())
+                    .unwrap_or_default();
+
+                if elapsed_since_trusted_consensus_state >= client_state.unbonding_period {
+                    return Err(Error::RevivalPastUnbondingPeriod {
+                        elapsed: elapsed_since_trusted_consensus_state,
+                        unbonding_period: client_state.unbonding_period,
+                    }
+                    .into());
+                }
+
+                options.trusting_period = client_state
+                    .trusting_period
+                    .saturating_add(elapsed_since_trusted_consensus_state)
+                    .min(client_state.unbonding_period);
+            }
+
             verify_header::<V, H>(
                 ctx,
                 &header,
                 client_id,
                 client_state.chain_id(),
-                &client_state.as_light_client_options()?,
+                &options,
                 verifier,
             )
         }
@@ -201,15 +259,11 @@ where
     }
 
     let latest_consensus_state: ConsensusStateType = {
-        match ctx.consensus_state(&ClientConsensusStatePath::new(
-            client_id.clone(),
-            client_state.latest_height.revision_number(),
-            client_state.latest_height.revision_height(),
-        )) {
+        match ctx.consensus_state(&client_state.latest_height.consensus_state_path(client_id)) {
             Ok(cs) => cs.try_into().map_err(Into::into)?,
             // if the client state does not have an associated consensus state for its latest height
-            // then it must be expired
-            Err(_) => return Ok(Status::Expired),
+            // (e.g. it was never stored or has been pruned) then its status cannot be determined
+            Err(_) => return Ok(Status::Unknown),
         }
     };
 
@@ -219,7 +273,7 @@ where
     let now = ctx.host_timestamp()?;
 
     if let Some(elapsed_since_latest_consensus_state) =
-        now.duration_since(&latest_consensus_state.timestamp().into())
+        now.duration_since(&latest_consensus_state.timestamp())
     {
         if elapsed_since_latest_consensus_state > client_state.trusting_period {
             return Ok(Status::Expired);
@@ -229,6 +283,102 @@ where
     Ok(Status::Active)
 }
 
+/// Check whether the client is allowed to process a client message as part of
+/// the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+///
+/// The conditions checked, based on the client [`status`], are:
+///
+/// - an `Active` client can always be updated;
+/// - an `Expired` client can be updated, and thereby revived by a valid
+///   header, only if `allow_update.after_expiry` is set. Otherwise, the update
+///   is rejected and the client can only be recovered through governance;
+/// - a `Frozen` client can be updated, and thereby revived by a valid header,
+///   only if `allow_update.after_misbehaviour` is set;
+/// - a client whose status is `Unknown` cannot be updated.
+pub fn check_update_allowed<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    match status(client_state, ctx, client_id)? {
+        Status::Expired if client_state.allow_update.after_expiry => Ok(()),
+        Status::Expired => Err(Error::ExpiredClientUpdateNotAllowed {
+            client_id: client_id.clone(),
+        }
+        .into()),
+        Status::Frozen if client_state.allow_update.after_misbehaviour => Ok(()),
+        status => status.verify_is_active(),
+    }
+}
+
+/// Audits the consensus states stored for the client identified by
+/// `client_id`, e.g. when investigating a suspect client.
+///
+/// The consensus states are checked in height order: their timestamps must be
+/// strictly increasing and their heights must not be of a revision above that
+/// of the client chain id. Consensus states from before an upgrade of the
+/// chain, which are of lower revisions, are thus consistent. The first
+/// inconsistency found is returned.
+pub fn audit_consensus_history<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<(), AuditError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let chain_id = &client_state.chain_id;
+
+    let mut heights = ctx
+        .consensus_state_heights(client_id)
+        .map_err(ClientError::from)?;
+    heights.sort();
+
+    let mut previous: Option<(Height, Timestamp)> = None;
+
+    for height in heights {
+        if height.revision_number() > chain_id.revision_number() {
+            return Err(AuditError::RevisionMismatch {
+                height,
+                chain_id: chain_id.to_string(),
+            });
+        }
+
+        let consensus_state: ConsensusStateType = ctx
+            .consensus_state(&height.consensus_state_path(client_id))
+            .map_err(ClientError::from)?
+            .try_into()
+            .map_err(Into::<ClientError>::into)?;
+        let timestamp = consensus_state.timestamp();
+
+        if let Some((previous_height, previous_timestamp)) = previous {
+            if timestamp <= previous_timestamp {
+                return Err(AuditError::NonMonotonicTimestamp {
+                    previous_height,
+                    previous_timestamp,
+                    height,
+                    timestamp,
+                });
+            }
+        }
+
+        previous = Some((height, timestamp));
+    }
+
+    Ok(())
+}
+
 /// Check that the subject and substitute client states match as part of
 /// the client recovery validation step.
 ///
@@ -15,7 +15,7 @@ use ibc_core_commitment_types::commitment::CommitmentRoot;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_primitives::Timestamp;
-use tendermint::{Hash, Time};
+use tendermint::Hash;
 
 /// Newtype wrapper around the `ConsensusState` type imported from the
 /// `ibc-client-tendermint-types` crate. This wrapper exists so that we can
@@ -34,8 +34,10 @@ impl ConsensusState {
         self.0
     }
 
-    pub fn timestamp(&self) -> Time {
-        self.0.timestamp
+    /// Returns the timestamp of the consensus state. See
+    /// [`ConsensusStateType::timestamp`] for details.
+    pub fn timestamp(&self) -> Timestamp {
+        self.0.timestamp()
     }
 
     pub fn next_validators_hash(&self) -> Hash {
@@ -93,6 +95,6 @@ impl ConsensusStateTrait for ConsensusState {
     }
 
     fn timestamp(&self) -> Timestamp {
-        self.0.timestamp.into()
+        self.0.timestamp()
     }
 }
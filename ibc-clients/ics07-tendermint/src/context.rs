@@ -0,0 +1,46 @@
+//! Defines the context hosts provide to Tendermint light clients, on top of
+//! the ICS-02 client contexts.
+
+use ibc_core_client::context::{ExtClientExecutionContext, ExtClientValidationContext};
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use tendermint::validator::Set as ValidatorSet;
+
+/// The default maximum number of validators in the validator sets of the
+/// headers Tendermint light clients accept, i.e. the maximum number of votes
+/// in a CometBFT commit.
+pub const DEFAULT_MAX_HEADER_VALIDATORS: usize = 10_000;
+
+/// Extends the [`ExtClientValidationContext`] with the methods specific to
+/// Tendermint light clients, all of which have default implementations.
+pub trait TmValidationContext: ExtClientValidationContext {
+    /// Returns the maximum number of validators in the validator sets of the
+    /// headers Tendermint light clients accept, which bounds the cost of
+    /// verifying them.
+    ///
+    /// The default implementation returns [`DEFAULT_MAX_HEADER_VALIDATORS`].
+    fn max_header_validators(&self) -> usize {
+        DEFAULT_MAX_HEADER_VALIDATORS
+    }
+
+    /// Returns the next validator set of the chain tracked by the client
+    /// identified by `client_id` as of `trusted_height`, if the host has it
+    /// locally, e.g. through direct access to the counterparty chain's state.
+    ///
+    /// Headers are verified against this set rather than the relayer-supplied
+    /// one whenever it is available. The default implementation returns
+    /// `None`.
+    fn trusted_next_validator_set(
+        &self,
+        _client_id: &ClientId,
+        _trusted_height: Height,
+    ) -> Option<ValidatorSet> {
+        None
+    }
+}
+
+/// A trait alias for types that implement both [`TmValidationContext`] and
+/// [`ExtClientExecutionContext`], auto-implemented for such types.
+pub trait TmExecutionContext: TmValidationContext + ExtClientExecutionContext {}
+
+impl<T> TmExecutionContext for T where T: TmValidationContext + ExtClientExecutionContext {}
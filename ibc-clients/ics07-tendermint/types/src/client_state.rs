@@ -10,7 +10,7 @@ use ibc_core_client_types::Height;
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_host_types::identifiers::ChainId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::ZERO_DURATION;
+use ibc_primitives::{Timestamp, ToVec, ZERO_DURATION};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
@@ -18,12 +18,46 @@ use tendermint::chain::id::MAX_LENGTH as MaxChainIdLen;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 use tendermint_light_client_verifier::options::Options;
 
+use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::error::Error;
 use crate::header::Header as TmHeader;
 use crate::trust_threshold::TrustThreshold;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
+/// Additional `Any` type URLs under which a Tendermint [`ClientState`] is
+/// accepted when decoding, e.g. for forks that vendor the Tendermint light
+/// client protos under a different package.
+///
+/// [`TENDERMINT_CLIENT_STATE_TYPE_URL`] is always accepted and remains the
+/// only type URL produced on encode. See [`DecodeOptions::type_url_aliases`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TmTypeUrlAliases {
+    type_urls: Vec<String>,
+}
+
+impl TmTypeUrlAliases {
+    /// Creates a registry accepting only the canonical type URL.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `type_url` as an alias of the canonical type URL.
+    pub fn with_alias(mut self, type_url: impl Into<String>) -> Self {
+        let type_url = type_url.into();
+        if !self.accepts(&type_url) {
+            self.type_urls.push(type_url);
+        }
+        self
+    }
+
+    /// Returns whether a client state published under `type_url` is decoded
+    /// as a Tendermint client state.
+    pub fn accepts(&self, type_url: &str) -> bool {
+        type_url == TENDERMINT_CLIENT_STATE_TYPE_URL || self.type_urls.iter().any(|u| u == type_url)
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct AllowUpdate {
@@ -31,6 +65,137 @@ pub struct AllowUpdate {
     pub after_misbehaviour: bool,
 }
 
+/// The height at which a client was frozen, if it is frozen at all.
+///
+/// In its protobuf form, a frozen height of `0` means "not frozen". See:
+/// https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrozenHeight(Option<Height>);
+
+impl FrozenHeight {
+    /// The frozen height of a client that is not frozen.
+    pub const fn not_frozen() -> Self {
+        Self(None)
+    }
+
+    /// The frozen height set upon misbehaviour, which is the same for all
+    /// misbehaviour. This aligns with the
+    /// [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
+    /// implementation.
+    pub fn misbehaviour_sentinel() -> Self {
+        Self(Some(Height::min(0)))
+    }
+
+    /// The frozen height of a client frozen at `height`.
+    pub fn at(height: Height) -> Self {
+        Self(Some(height))
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns whether the client was frozen upon misbehaviour, as opposed to
+    /// at an explicit height.
+    pub fn is_sentinel(&self) -> bool {
+        self.0 == Some(Height::min(0))
+    }
+
+    pub fn height(&self) -> Option<Height> {
+        self.0
+    }
+}
+
+impl From<RawHeight> for FrozenHeight {
+    fn from(raw: RawHeight) -> Self {
+        Self(Height::try_from(raw).ok())
+    }
+}
+
+impl From<FrozenHeight> for RawHeight {
+    fn from(frozen_height: FrozenHeight) -> Self {
+        // NOTE: The protobuf encoded `frozen_height` of an active client
+        // must be set to `0` so that `ibc-go` driven chains can properly
+        // decode the `ClientState` value.
+        frozen_height.0.map(Into::into).unwrap_or(RawHeight {
+            revision_number: 0,
+            revision_height: 0,
+        })
+    }
+}
+
+/// A single field that differs between two client states, with both values
+/// rendered for human review, as returned by [`ClientState::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub from: String,
+    pub to: String,
+}
+
+/// Light client [`Options`] together with the unbonding period of the
+/// client, which the former do not include but some verification flows need,
+/// e.g. to check that a header is not older than the unbonding period.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedOptions {
+    pub options: Options,
+    pub unbonding_period: Duration,
+}
+
+/// An estimate of the cost of verifying a header, as returned by
+/// [`ClientState::estimate_verify_cost`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerifyCost {
+    /// The size of the validator set of the header.
+    pub validator_set_size: usize,
+    /// Whether the header is at the height directly following its trusted
+    /// height, in which case the trusted validators need not be checked.
+    pub is_adjacent: bool,
+    /// The estimated cost, in units of signature verifications.
+    pub cost_units: u64,
+}
+
+/// Options for decoding a [`ClientState`] with
+/// [`ClientState::try_from_any_with_options`] or, for the options applying to
+/// its raw form, [`ClientState::try_from_raw_with_options`].
+///
+/// The default options are as permissive as the plain `TryFrom` conversions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// Rejects raw client states setting either of the deprecated
+    /// `allow_update_after_expiry` and `allow_update_after_misbehaviour`
+    /// flags.
+    pub reject_deprecated_allow_update: bool,
+    /// Type URLs besides the canonical one under which client states are
+    /// accepted.
+    pub type_url_aliases: TmTypeUrlAliases,
+    /// Rejects client states whose encoded value is larger than this many
+    /// bytes before decoding them.
+    pub max_encoded_len: Option<usize>,
+}
+
+impl DecodeOptions {
+    fn check_allow_update(
+        &self,
+        after_expiry: bool,
+        after_misbehaviour: bool,
+    ) -> Result<(), Error> {
+        if self.reject_deprecated_allow_update && (after_expiry || after_misbehaviour) {
+            return Err(Error::DeprecatedAllowUpdateSet {
+                after_expiry,
+                after_misbehaviour,
+            });
+        }
+
+        Ok(())
+    }
+}
+
 /// Defines data structure for Tendermint client state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -44,7 +209,7 @@ pub struct ClientState {
     pub proof_specs: ProofSpecs,
     pub upgrade_path: Vec<String>,
     pub allow_update: AllowUpdate,
-    pub frozen_height: Option<Height>,
+    pub frozen_height: FrozenHeight,
 }
 
 impl ClientState {
@@ -58,7 +223,7 @@ impl ClientState {
         latest_height: Height,
         proof_specs: ProofSpecs,
         upgrade_path: Vec<String>,
-        frozen_height: Option<Height>,
+        frozen_height: FrozenHeight,
         allow_update: AllowUpdate,
     ) -> Self {
         Self {
@@ -98,13 +263,34 @@ impl ClientState {
             latest_height,
             proof_specs,
             upgrade_path,
-            None, // New valid client must not be frozen.
+            FrozenHeight::not_frozen(), // New valid client must not be frozen.
             allow_update,
         );
         client_state.validate()?;
         Ok(client_state)
     }
 
+    /// Builds the client state and consensus state with which to create a
+    /// client trusting `header`.
+    ///
+    /// The parameters of the client are taken from `chain_params`, while its
+    /// latest height is that of the header on the chain of `chain_params`,
+    /// which the header must belong to. The resulting client state is not
+    /// frozen and is validated.
+    pub fn from_trusted_header(
+        chain_params: Self,
+        header: &TmHeader,
+    ) -> Result<(Self, TmConsensusState), Error> {
+        let client_state = Self {
+            latest_height: header.to_height(&chain_params.chain_id)?,
+            frozen_height: FrozenHeight::not_frozen(),
+            ..chain_params
+        };
+        client_state.validate()?;
+
+        Ok((client_state, TmConsensusState::from(header.clone())))
+    }
+
     pub fn with_header(self, header: TmHeader) -> Result<Self, Error> {
         Ok(Self {
             latest_height: max(header.height(), self.latest_height),
@@ -114,11 +300,31 @@ impl ClientState {
 
     pub fn with_frozen_height(self, h: Height) -> Self {
         Self {
-            frozen_height: Some(h),
+            frozen_height: FrozenHeight::at(h),
             ..self
         }
     }
 
+    /// Sets the trusting period, checking it against the unbonding period.
+    pub fn with_trusting_period(self, trusting_period: Duration) -> Result<Self, Error> {
+        let client_state = Self {
+            trusting_period,
+            ..self
+        };
+        client_state.validate_periods()?;
+        Ok(client_state)
+    }
+
+    /// Sets the unbonding period, checking it against the trusting period.
+    pub fn with_unbonding_period(self, unbonding_period: Duration) -> Result<Self, Error> {
+        let client_state = Self {
+            unbonding_period,
+            ..self
+        };
+        client_state.validate_periods()?;
+        Ok(client_state)
+    }
+
     pub fn validate(&self) -> Result<(), Error> {
         self.chain_id.validate_length(3, MaxChainIdLen as u64)?;
 
@@ -136,6 +342,47 @@ impl ClientState {
         )
         .map_err(Error::InvalidTendermintTrustThreshold)?;
 
+        self.validate_periods()?;
+
+        if self.max_clock_drift <= Duration::new(0, 0) {
+            return Err(Error::InvalidMaxClockDrift {
+                reason: "ClientState max-clock-drift must be greater than zero".to_string(),
+            });
+        }
+
+        // Note that chain identifiers without a revision suffix have a revision
+        // number of 0, so their latest height must be at revision 0 as well.
+        if self.latest_height.revision_number() != self.chain_id.revision_number() {
+            return Err(Error::InvalidLatestHeight {
+                reason: format!(
+                    "ClientState latest-height revision number ({}) must match chain-id ({}) version ({})",
+                    self.latest_height.revision_number(),
+                    self.chain_id,
+                    self.chain_id.revision_number()
+                ),
+            });
+        }
+
+        // Sanity checks on client proof specs
+        self.proof_specs.validate()?;
+
+        // `upgrade_path` itself may be empty, but if not then each key must be non-empty
+        for (idx, key) in self.upgrade_path.iter().enumerate() {
+            if key.trim().is_empty() {
+                return Err(Error::Validation {
+                    reason: format!(
+                        "ClientState upgrade-path key at index {idx:?} cannot be empty"
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the trusting and unbonding periods, i.e. that both are
+    /// non-zero and that the trusting period is the smaller one.
+    fn validate_periods(&self) -> Result<(), Error> {
         // Basic validation of trusting period and unbonding period: each should be non-zero.
         if self.trusting_period <= Duration::new(0, 0) {
             return Err(Error::InvalidTrustThreshold {
@@ -158,36 +405,76 @@ impl ClientState {
         if self.trusting_period >= self.unbonding_period {
             return Err(Error::InvalidTrustThreshold {
                 reason: format!(
-                "ClientState trusting period ({:?}) must be smaller than unbonding period ({:?})", self.trusting_period, self.unbonding_period
+                "ClientState trusting period ({:?}) must be smaller than unbonding period ({:?}); a trusting period of 2/3 of the unbonding period is recommended, see `ClientState::clamp_trusting_period`", self.trusting_period, self.unbonding_period
             ),
             });
         }
 
-        if self.max_clock_drift <= Duration::new(0, 0) {
-            return Err(Error::InvalidMaxClockDrift {
-                reason: "ClientState max-clock-drift must be greater than zero".to_string(),
+        Ok(())
+    }
+
+    /// Lowers the trusting period to 2/3 of the unbonding period if it is not
+    /// smaller than the unbonding period, which [`ClientState::validate`]
+    /// rejects. Client states with a smaller trusting period are returned
+    /// unchanged.
+    ///
+    /// This lets tooling generating client configurations auto-correct them.
+    /// Other invalid parameters, e.g. a zero unbonding period, are left as
+    /// is.
+    pub fn clamp_trusting_period(mut self) -> Self {
+        if self.trusting_period >= self.unbonding_period {
+            self.trusting_period = self.unbonding_period / 3 * 2;
+        }
+
+        self
+    }
+
+    /// Checks that the trust level of this client is at least `min`.
+    ///
+    /// Unlike [`ClientState::validate`], which only rejects a zero trust
+    /// level, this lets cautious integrators, e.g. relayers, refuse clients
+    /// whose trust level is dangerously low. Trust levels are compared by
+    /// value.
+    pub fn validate_trust_floor(&self, min: TrustThreshold) -> Result<(), Error> {
+        if self.trust_level.cmp_value(&min).is_lt() {
+            return Err(Error::InvalidTrustThreshold {
+                reason: format!(
+                    "ClientState trust-level ({}) is below the required minimum ({min})",
+                    self.trust_level
+                ),
             });
         }
 
-        if self.latest_height.revision_number() != self.chain_id.revision_number() {
-            return Err(Error::InvalidLatestHeight {
-                reason: "ClientState latest-height revision number must match chain-id version"
-                    .to_string(),
+        Ok(())
+    }
+
+    /// Checks that the max clock drift of this client is at most `max`.
+    ///
+    /// Unlike [`ClientState::validate`], which only rejects a zero max clock
+    /// drift, this lets cautious integrators refuse clients whose clock drift
+    /// is so large that it effectively disables time-based security.
+    pub fn validate_clock_drift_max(&self, max: Duration) -> Result<(), Error> {
+        if self.max_clock_drift > max {
+            return Err(Error::InvalidMaxClockDrift {
+                reason: format!(
+                    "ClientState max clock drift ({:?}) exceeds the allowed maximum ({max:?})",
+                    self.max_clock_drift
+                ),
             });
         }
 
-        // Sanity checks on client proof specs
-        self.proof_specs.validate()?;
+        Ok(())
+    }
 
-        // `upgrade_path` itself may be empty, but if not then each key must be non-empty
-        for (idx, key) in self.upgrade_path.iter().enumerate() {
-            if key.trim().is_empty() {
-                return Err(Error::Validation {
-                    reason: format!(
-                        "ClientState upgrade-path key at index {idx:?} cannot be empty"
-                    ),
-                });
-            }
+    /// Checks that the chain id of this client is one of `allowed`, e.g. to
+    /// guard against creating clients for unintended chains.
+    ///
+    /// This is an application-level policy check, not part of the IBC spec.
+    pub fn verify_chain_id_allowed(&self, allowed: &[ChainId]) -> Result<(), Error> {
+        if !allowed.contains(&self.chain_id) {
+            return Err(Error::ChainIdNotAllowed {
+                chain_id: self.chain_id.to_string(),
+            });
         }
 
         Ok(())
@@ -198,6 +485,171 @@ impl ClientState {
         Some(2 * self.trusting_period / 3)
     }
 
+    /// Returns how long until the client should be refreshed, given the
+    /// timestamp of its latest consensus state and the current time `now`,
+    /// i.e. the [`refresh_time`](ClientState::refresh_time) left once the
+    /// time elapsed since then is deducted.
+    ///
+    /// Returns `Some(ZERO_DURATION)` if the refresh is already overdue, and
+    /// `None` if the consensus state is timestamped after `now`, or either
+    /// timestamp is unset.
+    pub fn time_until_refresh(
+        &self,
+        latest_consensus_timestamp: Timestamp,
+        now: Timestamp,
+    ) -> Option<Duration> {
+        let elapsed = now.duration_since(&latest_consensus_timestamp)?;
+
+        Some(
+            self.refresh_time()?
+                .checked_sub(elapsed)
+                .unwrap_or(ZERO_DURATION),
+        )
+    }
+
+    /// Returns the ratio of the trusting period to the unbonding period.
+    ///
+    /// A valid client state has a ratio within `(0, 1)`. The closer it gets to
+    /// `1`, the less time there is to submit evidence of misbehaviour before
+    /// the validators of the counterparty chain are able to unbond.
+    pub fn trusting_period_safety_margin(&self) -> f64 {
+        self.trusting_period.as_secs_f64() / self.unbonding_period.as_secs_f64()
+    }
+
+    /// Returns a warning message if the trusting period to unbonding period
+    /// ratio exceeds the given `threshold`. This allows relayers to surface
+    /// risky client configurations without rejecting them.
+    pub fn warn_if_unsafe(&self, threshold: f64) -> Option<String> {
+        let ratio = self.trusting_period_safety_margin();
+
+        (ratio > threshold).then(|| {
+            format!(
+                "ClientState trusting period ({:?}) is {ratio:.2} of the unbonding period ({:?}), which exceeds the safety threshold of {threshold:.2}",
+                self.trusting_period, self.unbonding_period
+            )
+        })
+    }
+
+    /// Returns the fields that differ between `self` and `other`, e.g. to
+    /// audit the changes an upgraded client state introduces before a
+    /// governance proposal applies it.
+    pub fn diff(&self, other: &ClientState) -> Vec<FieldChange> {
+        // Destructure so that newly added fields can't be forgotten here.
+        let Self {
+            chain_id,
+            trust_level,
+            trusting_period,
+            unbonding_period,
+            max_clock_drift,
+            latest_height,
+            proof_specs,
+            upgrade_path,
+            allow_update,
+            frozen_height,
+        } = self;
+
+        let mut changes = Vec::new();
+        let mut compare = |field: &'static str, changed: bool, from: String, to: String| {
+            if changed {
+                changes.push(FieldChange { field, from, to });
+            }
+        };
+
+        compare(
+            "chain_id",
+            chain_id != &other.chain_id,
+            chain_id.to_string(),
+            other.chain_id.to_string(),
+        );
+        // Compare the encoded terms rather than the value, so that e.g.
+        // re-encoding 1/3 as 2/6 is reported as well.
+        compare(
+            "trust_level",
+            (trust_level.numerator(), trust_level.denominator())
+                != (
+                    other.trust_level.numerator(),
+                    other.trust_level.denominator(),
+                ),
+            trust_level.to_string(),
+            other.trust_level.to_string(),
+        );
+        compare(
+            "trusting_period",
+            trusting_period != &other.trusting_period,
+            format!("{trusting_period:?}"),
+            format!("{:?}", other.trusting_period),
+        );
+        compare(
+            "unbonding_period",
+            unbonding_period != &other.unbonding_period,
+            format!("{unbonding_period:?}"),
+            format!("{:?}", other.unbonding_period),
+        );
+        compare(
+            "max_clock_drift",
+            max_clock_drift != &other.max_clock_drift,
+            format!("{max_clock_drift:?}"),
+            format!("{:?}", other.max_clock_drift),
+        );
+        compare(
+            "latest_height",
+            latest_height != &other.latest_height,
+            latest_height.to_string(),
+            other.latest_height.to_string(),
+        );
+        compare(
+            "proof_specs",
+            proof_specs != &other.proof_specs,
+            format!("{proof_specs:?}"),
+            format!("{:?}", other.proof_specs),
+        );
+        compare(
+            "upgrade_path",
+            upgrade_path != &other.upgrade_path,
+            format!("{upgrade_path:?}"),
+            format!("{:?}", other.upgrade_path),
+        );
+        compare(
+            "allow_update",
+            allow_update != &other.allow_update,
+            format!("{allow_update:?}"),
+            format!("{:?}", other.allow_update),
+        );
+        compare(
+            "frozen_height",
+            frozen_height != &other.frozen_height,
+            format!("{:?}", frozen_height.height()),
+            format!("{:?}", other.frozen_height.height()),
+        );
+
+        changes
+    }
+
+    /// Checks that `self` and `other` agree on the chain-chosen parameters,
+    /// i.e. `chain_id`, `proof_specs`, `unbonding_period` and `upgrade_path`,
+    /// so that consensus states of one can be safely copied over to the other
+    /// during client recovery. Returns the differing fields otherwise.
+    pub fn is_recovery_compatible(&self, other: &ClientState) -> Result<(), Vec<FieldChange>> {
+        const CHAIN_CHOSEN_FIELDS: [&str; 4] = [
+            "chain_id",
+            "proof_specs",
+            "unbonding_period",
+            "upgrade_path",
+        ];
+
+        let incompatible: Vec<_> = self
+            .diff(other)
+            .into_iter()
+            .filter(|change| CHAIN_CHOSEN_FIELDS.contains(&change.field))
+            .collect();
+
+        if incompatible.is_empty() {
+            Ok(())
+        } else {
+            Err(incompatible)
+        }
+    }
+
     /// Helper method to produce a [`Options`] struct for use in
     /// Tendermint-specific light client verification.
     pub fn as_light_client_options(&self) -> Result<Options, Error> {
@@ -212,12 +664,47 @@ impl ClientState {
         })
     }
 
+    /// Like [`as_light_client_options`](Self::as_light_client_options), but
+    /// additionally exposes the unbonding period of the client.
+    pub fn as_extended_light_client_options(&self) -> Result<ExtendedOptions, Error> {
+        Ok(ExtendedOptions {
+            options: self.as_light_client_options()?,
+            unbonding_period: self.unbonding_period,
+        })
+    }
+
     pub fn chain_id(&self) -> &ChainId {
         &self.chain_id
     }
 
+    /// Estimates the cost of verifying `header` against this client, without
+    /// verifying it.
+    ///
+    /// The commit of the header may need to be checked for every validator of
+    /// its validator set. Unless the update is adjacent, the commit is also
+    /// checked for every trusted next validator to meet the trust threshold.
+    pub fn estimate_verify_cost(&self, header: &TmHeader) -> VerifyCost {
+        let validator_set_size = header.validator_set.validators().len();
+
+        let is_adjacent = header
+            .to_height(&self.chain_id)
+            .is_ok_and(|height| header.trusted_height.increment() == height);
+
+        let trusted_validator_set_size = if is_adjacent {
+            0
+        } else {
+            header.trusted_next_validator_set.validators().len()
+        };
+
+        VerifyCost {
+            validator_set_size,
+            is_adjacent,
+            cost_units: (validator_set_size + trusted_validator_set_size) as u64,
+        }
+    }
+
     pub fn is_frozen(&self) -> bool {
-        self.frozen_height.is_some()
+        self.frozen_height.is_frozen()
     }
 
     // Resets custom fields to zero values (used in `update_client`)
@@ -226,13 +713,65 @@ impl ClientState {
         self.trust_level = TrustThreshold::ZERO;
         self.allow_update.after_expiry = false;
         self.allow_update.after_misbehaviour = false;
-        self.frozen_height = None;
+        self.frozen_height = FrozenHeight::not_frozen();
         self.max_clock_drift = ZERO_DURATION;
     }
 }
 
 impl Protobuf<RawTmClientState> for ClientState {}
 
+#[cfg(feature = "cbor")]
+impl ClientState {
+    /// Encodes the client state as CBOR, by way of its protobuf
+    /// representation [`RawTmClientState`].
+    pub fn to_cbor(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&RawTmClientState::from(self.clone()), &mut bytes).map_err(|e| {
+            Error::InvalidRawClientState {
+                reason: format!("failed to encode client state as CBOR: {e}"),
+            }
+        })?;
+
+        Ok(bytes)
+    }
+
+    /// Decodes a client state from CBOR produced by
+    /// [`to_cbor`](ClientState::to_cbor), validating it like one decoded from
+    /// protobuf.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, Error> {
+        let raw: RawTmClientState =
+            ciborium::from_reader(bytes).map_err(|e| Error::InvalidRawClientState {
+                reason: format!("failed to decode client state from CBOR: {e}"),
+            })?;
+
+        raw.try_into()
+    }
+}
+
+/// Borsh encodes the client state by way of its protobuf encoding, so that
+/// decoding goes through the same validation as protobuf.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ClientState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ClientState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = Vec::<u8>::deserialize_reader(reader)?;
+        Ok(Protobuf::<RawTmClientState>::decode_vec(&bytes)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData)?)
+    }
+}
+
 impl TryFrom<RawTmClientState> for ClientState {
     type Error = Error;
 
@@ -240,10 +779,7 @@ impl TryFrom<RawTmClientState> for ClientState {
         let chain_id = ChainId::from_str(raw.chain_id.as_str())?;
 
         let trust_level = {
-            let trust_level = raw
-                .trust_level
-                .clone()
-                .ok_or(Error::MissingTrustingPeriod)?;
+            let trust_level = raw.trust_level.clone().ok_or(Error::MissingTrustLevel)?;
             trust_level
                 .try_into()
                 .map_err(|e| Error::InvalidTrustThreshold {
@@ -255,17 +791,17 @@ impl TryFrom<RawTmClientState> for ClientState {
             .trusting_period
             .ok_or(Error::MissingTrustingPeriod)?
             .try_into()
-            .map_err(|_| Error::MissingTrustingPeriod)?;
+            .map_err(|_| Error::InvalidTrustingPeriod)?;
 
         let unbonding_period = raw
             .unbonding_period
             .ok_or(Error::MissingUnbondingPeriod)?
             .try_into()
-            .map_err(|_| Error::MissingUnbondingPeriod)?;
+            .map_err(|_| Error::InvalidUnbondingPeriod)?;
 
         let max_clock_drift = raw
             .max_clock_drift
-            .ok_or(Error::NegativeMaxClockDrift)?
+            .ok_or(Error::MissingMaxClockDrift)?
             .try_into()
             .map_err(|_| Error::NegativeMaxClockDrift)?;
 
@@ -273,13 +809,11 @@ impl TryFrom<RawTmClientState> for ClientState {
             .latest_height
             .ok_or(Error::MissingLatestHeight)?
             .try_into()
-            .map_err(|_| Error::MissingLatestHeight)?;
+            .map_err(|e: ClientError| Error::InvalidLatestHeight {
+                reason: e.to_string(),
+            })?;
 
-        // NOTE: In `RawClientState`, a `frozen_height` of `0` means "not
-        // frozen". See:
-        // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
-        let frozen_height =
-            Height::try_from(raw.frozen_height.ok_or(Error::MissingFrozenHeight)?).ok();
+        let frozen_height = raw.frozen_height.ok_or(Error::MissingFrozenHeight)?.into();
 
         // We use set this deprecated field just so that we can properly convert
         // it back in its raw form
@@ -306,6 +840,25 @@ impl TryFrom<RawTmClientState> for ClientState {
     }
 }
 
+impl ClientState {
+    /// Decodes a client state from its raw form like the `TryFrom`
+    /// conversion, applying the stricter checks enabled in `options`.
+    pub fn try_from_raw_with_options(
+        raw: RawTmClientState,
+        options: &DecodeOptions,
+    ) -> Result<Self, Error> {
+        #[allow(deprecated)]
+        let (after_expiry, after_misbehaviour) = (
+            raw.allow_update_after_expiry,
+            raw.allow_update_after_misbehaviour,
+        );
+
+        options.check_allow_update(after_expiry, after_misbehaviour)?;
+
+        raw.try_into()
+    }
+}
+
 impl From<ClientState> for RawTmClientState {
     fn from(value: ClientState) -> Self {
         #[allow(deprecated)]
@@ -315,15 +868,7 @@ impl From<ClientState> for RawTmClientState {
             trusting_period: Some(value.trusting_period.into()),
             unbonding_period: Some(value.unbonding_period.into()),
             max_clock_drift: Some(value.max_clock_drift.into()),
-            // NOTE: The protobuf encoded `frozen_height` of an active client
-            // must be set to `0` so that `ibc-go` driven chains can properly
-            // decode the `ClientState` value. In `RawClientState`, a
-            // `frozen_height` of `0` means "not frozen". See:
-            // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
-            frozen_height: Some(value.frozen_height.map(Into::into).unwrap_or(RawHeight {
-                revision_number: 0,
-                revision_height: 0,
-            })),
+            frozen_height: Some(value.frozen_height.into()),
             latest_height: Some(value.latest_height.into()),
             proof_specs: value.proof_specs.into(),
             upgrade_path: value.upgrade_path,
@@ -339,20 +884,98 @@ impl TryFrom<Any> for ClientState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
-            let client_state =
-                Protobuf::<RawTmClientState>::decode(value).map_err(|e| ClientError::Other {
-                    description: e.to_string(),
-                })?;
-            Ok(client_state)
+        Self::try_from_any_with_options(raw, &DecodeOptions::default())
+    }
+}
+
+impl ClientState {
+    /// Decodes a client state from an [`Any`] like the `TryFrom` conversion,
+    /// applying the checks and type URL aliases enabled in `options`.
+    pub fn try_from_any_with_options(
+        raw: Any,
+        options: &DecodeOptions,
+    ) -> Result<Self, ClientError> {
+        if let Some(max_bytes) = options.max_encoded_len {
+            if raw.value.len() > max_bytes {
+                return Err(Error::EncodedMessageTooLarge {
+                    type_url: raw.type_url,
+                    actual: raw.value.len(),
+                    max: max_bytes,
+                }
+                .into());
+            }
         }
 
-        match raw.type_url.as_str() {
-            TENDERMINT_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
-            _ => Err(ClientError::UnknownClientStateType {
+        if !options.type_url_aliases.accepts(&raw.type_url) {
+            return Err(ClientError::UnknownClientStateType {
                 client_state_type: raw.type_url,
-            }),
+            });
         }
+
+        let client_state: Self = Protobuf::<RawTmClientState>::decode(raw.value.as_slice())
+            .map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        // The deprecated flags are carried over as is, so they can be checked
+        // on the decoded client state.
+        options.check_allow_update(
+            client_state.allow_update.after_expiry,
+            client_state.allow_update.after_misbehaviour,
+        )?;
+
+        Ok(client_state)
+    }
+
+    /// Decodes a client state from an untrusted [`Any`], rejecting it before
+    /// decoding if its encoded value is larger than `max_bytes`.
+    ///
+    /// Trusted inputs can keep using the unbounded `TryFrom<Any>`.
+    ///
+    /// This is a shorthand for [`ClientState::try_from_any_with_options`] with
+    /// only [`DecodeOptions::max_encoded_len`] set.
+    pub fn decode_with_limit(any: &Any, max_bytes: usize) -> Result<Self, ClientError> {
+        Self::try_from_any_with_options(
+            any.clone(),
+            &DecodeOptions {
+                max_encoded_len: Some(max_bytes),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Decodes the protobuf-encoded client state `original` and re-encodes
+    /// it, failing unless the result is byte-identical to `original`.
+    ///
+    /// Hosts committing to the exact client state bytes can use this to
+    /// ensure that storing the decoded client state does not alter them,
+    /// which holds for any canonically encoded input.
+    pub fn reencode(original: &[u8]) -> Result<Vec<u8>, ClientError> {
+        let client_state = Protobuf::<RawTmClientState>::decode_vec(original).map_err(|e| {
+            Error::InvalidRawClientState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        let reencoded = Protobuf::<RawTmClientState>::encode_vec(client_state);
+
+        if reencoded != original {
+            return Err(Error::InvalidRawClientState {
+                reason: "client state is not canonically encoded".to_string(),
+            }
+            .into());
+        }
+
+        Ok(reencoded)
+    }
+
+    /// Returns the bytes a host commits to for this client state, i.e. the
+    /// protobuf encoding of its [`Any`] representation.
+    ///
+    /// These are the bytes that membership proofs of the client state, such
+    /// as those under its `ClientStatePath`, are verified against.
+    pub fn commitment_bytes(&self) -> Vec<u8> {
+        Any::from(self.clone()).to_vec()
     }
 }
 
@@ -411,6 +1034,15 @@ pub(crate) mod serde_tests {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "borsh")]
+    use borsh::{BorshDeserialize, BorshSerialize};
+    #[cfg(feature = "borsh")]
+    use ibc_core_commitment_types::commitment::CommitmentRoot;
+    use rstest::rstest;
+    use subtle_encoding::hex;
+    #[cfg(feature = "borsh")]
+    use tendermint::{Hash, Time};
+
     use super::*;
 
     #[derive(Clone, Debug, PartialEq)]
@@ -426,6 +1058,535 @@ mod tests {
         pub allow_update: AllowUpdate,
     }
 
+    fn dummy_client_state(trusting_period: Duration, unbonding_period: Duration) -> ClientState {
+        ClientState::new(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            trusting_period,
+            unbonding_period,
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos_sdk(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .expect("Never fails")
+    }
+
+    #[rstest]
+    #[case(Duration::from_secs(3), true)]
+    #[case(Duration::from_secs(10), true)]
+    #[case(Duration::from_secs(60 * 60), false)]
+    fn client_state_validate_clock_drift_max(
+        #[case] max_clock_drift: Duration,
+        #[case] is_valid: bool,
+    ) {
+        let client_state = ClientState {
+            max_clock_drift,
+            ..dummy_client_state(Duration::new(50, 0), Duration::new(100, 0))
+        };
+
+        assert!(client_state.validate().is_ok());
+        assert_eq!(
+            client_state
+                .validate_clock_drift_max(Duration::from_secs(10))
+                .is_ok(),
+            is_valid
+        );
+    }
+
+    #[rstest]
+    #[case(Duration::new(50, 0), Duration::new(100, 0), Duration::new(50, 0))]
+    #[case(
+        Duration::new(100, 0),
+        Duration::new(100, 0),
+        Duration::new(66, 666_666_666)
+    )]
+    #[case(
+        Duration::new(150, 0),
+        Duration::new(100, 0),
+        Duration::new(66, 666_666_666)
+    )]
+    // Clamping must not overflow for the longest unbonding periods.
+    #[case(Duration::MAX, Duration::MAX, Duration::MAX / 3 * 2)]
+    fn client_state_clamp_trusting_period(
+        #[case] trusting_period: Duration,
+        #[case] unbonding_period: Duration,
+        #[case] clamped_trusting_period: Duration,
+    ) {
+        let client_state = ClientState {
+            trusting_period,
+            unbonding_period,
+            ..dummy_client_state(Duration::new(50, 0), Duration::new(100, 0))
+        };
+
+        let clamped = client_state.clamp_trusting_period();
+        assert_eq!(clamped.trusting_period, clamped_trusting_period);
+        assert!(clamped.trusting_period < clamped.unbonding_period);
+    }
+
+    #[rstest]
+    #[case(Duration::new(50, 0), 0.9, 0.5, false)]
+    #[case(Duration::new(95, 0), 0.9, 0.95, true)]
+    #[case(Duration::new(95, 0), 0.99, 0.95, false)]
+    fn client_state_trusting_period_safety(
+        #[case] trusting_period: Duration,
+        #[case] threshold: f64,
+        #[case] safety_margin: f64,
+        #[case] warns: bool,
+    ) {
+        let client_state = dummy_client_state(trusting_period, Duration::new(100, 0));
+
+        assert_eq!(client_state.trusting_period_safety_margin(), safety_margin);
+        assert_eq!(client_state.warn_if_unsafe(threshold).is_some(), warns);
+    }
+
+    #[test]
+    fn client_state_decoded_but_never_verified() {
+        // Client states carry no verifier; the `ProdVerifier` is built on
+        // demand at verification time, so decoding stays cheap and the
+        // decoded value must be indistinguishable from the original.
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+
+        let decoded = ClientState::try_from(Any::from(client_state.clone())).expect("Never fails");
+
+        assert_eq!(decoded, client_state);
+        assert_eq!(
+            decoded.as_light_client_options().expect("Never fails"),
+            client_state.as_light_client_options().expect("Never fails")
+        );
+    }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(1, false)]
+    fn client_state_decode_with_limit(#[case] missing_bytes: usize, #[case] is_ok: bool) {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        let any = Any::from(client_state.clone());
+
+        let decoded = ClientState::decode_with_limit(&any, any.value.len() - missing_bytes);
+        assert_eq!(decoded.ok(), is_ok.then_some(client_state));
+    }
+
+    #[rstest]
+    #[case(TrustThreshold::new(1, 4).expect("Never fails"), false)]
+    #[case(TrustThreshold::ONE_THIRD, true)]
+    #[case(TrustThreshold::new(2, 6).expect("Never fails"), true)]
+    #[case(TrustThreshold::TWO_THIRDS, true)]
+    fn client_state_trust_floor(#[case] trust_level: TrustThreshold, #[case] is_ok: bool) {
+        let client_state = ClientState {
+            trust_level,
+            ..dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0))
+        };
+
+        assert_eq!(
+            client_state
+                .validate_trust_floor(TrustThreshold::ONE_THIRD)
+                .is_ok(),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn client_state_diff() {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        let upgraded = dummy_client_state(Duration::new(32000, 0), Duration::new(96_000, 0));
+
+        assert!(client_state.diff(&client_state).is_empty());
+
+        assert_eq!(
+            client_state.diff(&upgraded),
+            vec![
+                FieldChange {
+                    field: "trusting_period",
+                    from: "64000s".to_string(),
+                    to: "32000s".to_string(),
+                },
+                FieldChange {
+                    field: "unbonding_period",
+                    from: "128000s".to_string(),
+                    to: "96000s".to_string(),
+                },
+            ]
+        );
+
+        let mut reencoded = client_state.clone();
+        reencoded.trust_level = TrustThreshold::new(2, 6).expect("Never fails");
+        assert_eq!(
+            client_state.diff(&reencoded),
+            vec![FieldChange {
+                field: "trust_level",
+                from: "1/3".to_string(),
+                to: "2/6".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn client_state_recovery_compatibility() {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+
+        // Client-chosen parameters may differ.
+        let mut substitute = client_state.clone();
+        substitute.trusting_period = Duration::new(32000, 0);
+        substitute.trust_level = TrustThreshold::TWO_THIRDS;
+        substitute.latest_height = Height::new(1, 20).expect("no error");
+        assert_eq!(client_state.is_recovery_compatible(&substitute), Ok(()));
+
+        substitute.chain_id = ChainId::new("ibc-2").expect("no error");
+        substitute.unbonding_period = Duration::new(96_000, 0);
+        substitute.upgrade_path = vec!["upgrade".to_string(), "upgradedIBCState".to_string()];
+
+        let fields: Vec<_> = client_state
+            .is_recovery_compatible(&substitute)
+            .expect_err("chain-chosen parameters differ")
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        assert_eq!(fields, ["chain_id", "unbonding_period", "upgrade_path"]);
+    }
+
+    #[test]
+    fn client_state_debug_prints_configuration_only() {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        let debug = format!("{client_state:?}");
+
+        assert!(debug.contains("ibc-0"), "{debug}");
+        assert!(!debug.to_lowercase().contains("verifier"), "{debug}");
+    }
+
+    #[rstest]
+    #[case(Duration::new(96_000, 0), true)]
+    #[case(Duration::new(256_000, 0), false)]
+    #[case(Duration::ZERO, false)]
+    fn client_state_with_trusting_period(#[case] trusting_period: Duration, #[case] is_ok: bool) {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+
+        let adjusted = client_state.with_trusting_period(trusting_period);
+        assert_eq!(
+            adjusted
+                .map(|client_state| client_state.trusting_period)
+                .ok(),
+            is_ok.then_some(trusting_period)
+        );
+    }
+
+    #[rstest]
+    #[case(Duration::new(100_000, 0), true)]
+    #[case(Duration::new(32_000, 0), false)]
+    fn client_state_with_unbonding_period(#[case] unbonding_period: Duration, #[case] is_ok: bool) {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+
+        let adjusted = client_state.with_unbonding_period(unbonding_period);
+        assert_eq!(
+            adjusted
+                .map(|client_state| client_state.unbonding_period)
+                .ok(),
+            is_ok.then_some(unbonding_period)
+        );
+    }
+
+    #[test]
+    fn client_state_extended_light_client_options() {
+        let client_state = dummy_client_state(Duration::new(50, 0), Duration::new(100, 0));
+
+        let extended = client_state
+            .as_extended_light_client_options()
+            .expect("no error");
+
+        assert_eq!(
+            extended.options,
+            client_state.as_light_client_options().expect("no error")
+        );
+        assert_eq!(extended.options.trusting_period, Duration::new(50, 0));
+        assert_eq!(extended.options.clock_drift, Duration::new(3, 0));
+        assert_eq!(extended.unbonding_period, Duration::new(100, 0));
+    }
+
+    // The refresh time is 2/3 of the trusting period, i.e. 600s.
+    #[rstest]
+    #[case(0, 100, Some(Duration::from_secs(500)))]
+    #[case(0, 601, Some(ZERO_DURATION))]
+    #[case(1, 0, None)]
+    fn client_state_time_until_refresh(
+        #[case] consensus_secs: u64,
+        #[case] now_secs: u64,
+        #[case] time_until_refresh: Option<Duration>,
+    ) {
+        let client_state = dummy_client_state(Duration::new(900, 0), Duration::new(1800, 0));
+        let genesis = Timestamp::from_nanoseconds(1_000_000_000_000).unwrap();
+        let after = |secs| (genesis + Duration::from_secs(secs)).unwrap();
+
+        assert_eq!(
+            client_state.time_until_refresh(after(consensus_secs), after(now_secs)),
+            time_until_refresh
+        );
+    }
+
+    #[rstest]
+    #[case(&["ibc-1", "ibc-0"], true)]
+    #[case(&["ibc-0"], true)]
+    #[case(&["ibc-1"], false)]
+    #[case(&[], false)]
+    fn client_state_chain_id_allow_list(#[case] allowed: &[&str], #[case] is_allowed: bool) {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        let allowed: Vec<_> = allowed
+            .iter()
+            .map(|id| ChainId::new(id).expect("no error"))
+            .collect();
+
+        match client_state.verify_chain_id_allowed(&allowed) {
+            Ok(()) => assert!(is_allowed),
+            Err(err) => {
+                assert!(!is_allowed, "{err}");
+                assert!(err.to_string().contains("ibc-0"), "{err}");
+            }
+        }
+    }
+
+    #[test]
+    fn client_state_decode_with_type_url_alias() {
+        let alias = "/vendored.lightclients.tendermint.v1.ClientState";
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+
+        let mut any: Any = client_state.clone().into();
+        assert_eq!(any.type_url, TENDERMINT_CLIENT_STATE_TYPE_URL);
+        any.type_url = alias.to_string();
+
+        assert!(ClientState::try_from(any.clone()).is_err());
+
+        let options = DecodeOptions {
+            type_url_aliases: TmTypeUrlAliases::new().with_alias(alias),
+            ..Default::default()
+        };
+        assert_eq!(
+            ClientState::try_from_any_with_options(any.clone(), &options).expect("no error"),
+            client_state
+        );
+
+        // The aliases combine with the other options.
+        let strict_options = DecodeOptions {
+            max_encoded_len: Some(any.value.len() - 1),
+            ..options
+        };
+        assert!(ClientState::try_from_any_with_options(any, &strict_options).is_err());
+
+        // Encoding always produces the canonical type URL.
+        let reencoded: Any = client_state.into();
+        assert_eq!(reencoded.type_url, TENDERMINT_CLIENT_STATE_TYPE_URL);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn client_state_cbor_roundtrip() {
+        let mut client_state =
+            dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        client_state.upgrade_path = vec!["upgrade".to_string(), "upgradedIBCState".to_string()];
+        client_state = client_state.with_frozen_height(Height::new(0, 5).expect("no error"));
+
+        let bytes = client_state.to_cbor().expect("no error");
+        assert_eq!(
+            ClientState::from_cbor(&bytes).expect("no error"),
+            client_state
+        );
+
+        assert!(ClientState::from_cbor(&bytes[..bytes.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn client_state_frozen_height_proto_roundtrip() {
+        let client_state = dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        let roundtrip = |frozen_height: FrozenHeight| {
+            let client_state = ClientState {
+                frozen_height,
+                ..client_state.clone()
+            };
+            let raw = RawTmClientState::from(client_state.clone());
+            let decoded = ClientState::try_from(raw.clone()).expect("no error");
+            assert_eq!(decoded, client_state);
+            raw.frozen_height.expect("frozen height is always encoded")
+        };
+
+        let raw = roundtrip(FrozenHeight::not_frozen());
+        assert_eq!((raw.revision_number, raw.revision_height), (0, 0));
+        assert!(!FrozenHeight::not_frozen().is_frozen());
+
+        let sentinel = FrozenHeight::misbehaviour_sentinel();
+        let raw = roundtrip(sentinel);
+        assert_eq!((raw.revision_number, raw.revision_height), (0, 1));
+        assert!(sentinel.is_frozen() && sentinel.is_sentinel());
+
+        let at_height = FrozenHeight::at(Height::new(0, 5).expect("no error"));
+        let raw = roundtrip(at_height);
+        assert_eq!((raw.revision_number, raw.revision_height), (0, 5));
+        assert!(at_height.is_frozen() && !at_height.is_sentinel());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn client_state_and_consensus_state_borsh_roundtrip() {
+        let mut client_state =
+            dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        client_state.upgrade_path = vec!["upgrade".to_string(), "upgradedIBCState".to_string()];
+        client_state = client_state.with_frozen_height(Height::new(0, 5).expect("no error"));
+
+        let bytes = client_state.try_to_vec().expect("no error");
+        assert_eq!(
+            ClientState::try_from_slice(&bytes).expect("no error"),
+            client_state
+        );
+        assert!(ClientState::try_from_slice(&bytes[..bytes.len() / 2]).is_err());
+
+        let consensus_state = TmConsensusState::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            Time::from_unix_timestamp(1_700_000_000, 0).expect("no error"),
+            Hash::Sha256([2; 32]),
+        );
+
+        let bytes = consensus_state.try_to_vec().expect("no error");
+        assert_eq!(
+            TmConsensusState::try_from_slice(&bytes).expect("no error"),
+            consensus_state
+        );
+    }
+
+    #[test]
+    fn client_state_reencode() {
+        // A client state tracking `cosmoshub-4` with the proof specs and
+        // upgrade path used by ibc-go, encoded as ibc-go stores it.
+        let original = hex::decode(concat!(
+            "0a0b636f736d6f736875622d341204080110031a040880ea4922040880df6e2a",
+            "02080a32003a07080410c0de810a42190a090801180120012a0100120c0a0200",
+            "0110211804200c300142190a090801180120012a0100120c0a02000110201801",
+            "200130014a07757067726164654a1075706772616465644942435374617465",
+        ))
+        .expect("valid hex");
+
+        let client_state = Protobuf::<RawTmClientState>::decode_vec(&original).expect("decodes");
+        assert_eq!(client_state.chain_id.as_str(), "cosmoshub-4");
+        assert!(!client_state.is_frozen());
+
+        assert_eq!(
+            ClientState::reencode(&original).expect("no error"),
+            original
+        );
+
+        // A field explicitly set to its default value is not canonical.
+        let mut explicit_default = original.clone();
+        explicit_default.extend_from_slice(&[0x50, 0x00]);
+        assert!(ClientState::reencode(&explicit_default).is_err());
+
+        assert!(ClientState::reencode(&original[..original.len() - 1]).is_err());
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, false)]
+    #[case(false, true)]
+    #[case(true, true)]
+    fn client_state_decode_options_deprecated_allow_update(
+        #[case] after_expiry: bool,
+        #[case] after_misbehaviour: bool,
+    ) {
+        let strict_options = DecodeOptions {
+            reject_deprecated_allow_update: true,
+            ..Default::default()
+        };
+
+        let mut raw = RawTmClientState::from(dummy_client_state(
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+        ));
+        #[allow(deprecated)]
+        {
+            raw.allow_update_after_expiry = after_expiry;
+            raw.allow_update_after_misbehaviour = after_misbehaviour;
+        }
+
+        let permissive =
+            ClientState::try_from_raw_with_options(raw.clone(), &DecodeOptions::default())
+                .expect("permissive decoding accepts the deprecated flags");
+        assert_eq!(permissive.allow_update.after_expiry, after_expiry);
+        assert_eq!(
+            permissive.allow_update.after_misbehaviour,
+            after_misbehaviour
+        );
+
+        let any = Any {
+            type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: raw.to_vec(),
+        };
+        assert_eq!(
+            ClientState::try_from_any_with_options(any, &strict_options).is_ok(),
+            !(after_expiry || after_misbehaviour)
+        );
+
+        let strict = ClientState::try_from_raw_with_options(raw, &strict_options);
+        if after_expiry || after_misbehaviour {
+            assert!(
+                matches!(strict, Err(Error::DeprecatedAllowUpdateSet { .. })),
+                "{strict:?}"
+            );
+        } else {
+            assert!(strict.is_ok(), "{strict:?}");
+        }
+    }
+
+    #[test]
+    fn client_state_latest_height_revision_mismatch_error() {
+        let mut client_state =
+            dummy_client_state(Duration::new(64000, 0), Duration::new(128_000, 0));
+        client_state.chain_id = ChainId::new("chain-5").unwrap();
+        client_state.latest_height = Height::new(0, 10).unwrap();
+
+        let err = client_state
+            .validate()
+            .expect_err("revision numbers mismatch");
+        let message = err.to_string();
+
+        assert!(matches!(err, Error::InvalidLatestHeight { .. }));
+        assert!(message.contains("revision number (0)"), "{message}");
+        assert!(
+            message.contains("chain-id (chain-5) version (5)"),
+            "{message}"
+        );
+    }
+
+    #[rstest]
+    #[case("ibc-5", 5, true)]
+    #[case("ibc-5", 0, false)]
+    #[case("myhost", 0, true)]
+    #[case("myhost", 1, false)]
+    fn client_state_chain_id_revision_matches_latest_height(
+        #[case] chain_id: &str,
+        #[case] revision_number: u64,
+        #[case] is_valid: bool,
+    ) {
+        let res = ClientState::new(
+            ChainId::new(chain_id).unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(revision_number, 10).expect("Never fails"),
+            ProofSpecs::cosmos_sdk(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        );
+
+        assert_eq!(
+            res.is_ok(),
+            is_valid,
+            "ClientState::new() failed for chain id {chain_id} and revision number {revision_number}: {res:?}"
+        );
+    }
+
     #[test]
     fn client_state_new() {
         // Define a "default" set of parameters to reuse throughout these tests.
@@ -436,7 +1597,7 @@ mod tests {
             unbonding_period: Duration::new(128_000, 0),
             max_clock_drift: Duration::new(3, 0),
             latest_height: Height::new(0, 10).expect("Never fails"),
-            proof_specs: ProofSpecs::cosmos(),
+            proof_specs: ProofSpecs::cosmos_sdk(),
             upgrade_path: Vec::new(),
             allow_update: AllowUpdate {
                 after_expiry: false,
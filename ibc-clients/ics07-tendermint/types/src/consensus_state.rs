@@ -1,12 +1,17 @@
 //! Defines Tendermint's `ConsensusState` type
 
 use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentRoot;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight as RawConsensusStateWithHeight;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
+use tendermint::crypto::Sha256;
 use tendermint::hash::Algorithm;
+use tendermint::merkle::MerkleHash;
 use tendermint::time::Time;
 use tendermint::Hash;
 use tendermint_proto::google::protobuf as tpb;
@@ -35,17 +40,78 @@ impl ConsensusState {
         }
     }
 
-    pub fn timestamp(&self) -> Time {
-        self.timestamp
+    /// Returns the block time of the header this consensus state was derived
+    /// from, as an IBC [`Timestamp`].
+    ///
+    /// This is the canonical reference for expiry: a client whose latest
+    /// consensus state is older than its trusting period as of the host time
+    /// is expired. The underlying tendermint [`Time`] is available through the
+    /// `timestamp` field.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp.into()
     }
 
     pub fn root(&self) -> CommitmentRoot {
         self.root.clone()
     }
+
+    pub fn next_validators_hash(&self) -> Hash {
+        self.next_validators_hash
+    }
+
+    /// Returns whether this consensus state commits to the same content as
+    /// `other`, i.e. has the same root, timestamp and next validators hash.
+    ///
+    /// Unlike the derived equality, this keeps ignoring any metadata this type
+    /// may carry in the future, which makes it suitable for detecting whether
+    /// two consensus states at the same height conflict.
+    pub fn content_eq(&self, other: &Self) -> bool {
+        self.root == other.root
+            && self.timestamp == other.timestamp
+            && self.next_validators_hash == other.next_validators_hash
+    }
+
+    /// Returns whether the trusted next validator set supplied by the relayer
+    /// in `header` hashes to the next validators hash of this consensus state.
+    pub fn matches_next_validators<H: MerkleHash + Sha256 + Default>(
+        &self,
+        header: &Header,
+    ) -> bool {
+        header.trusted_next_validator_set.hash_with::<H>() == self.next_validators_hash
+    }
+
+    /// Pairs this consensus state with the `height` it is stored at.
+    pub fn with_height(self, height: Height) -> ConsensusStateWithHeight {
+        ConsensusStateWithHeight::new(height, self)
+    }
 }
 
 impl Protobuf<RawConsensusState> for ConsensusState {}
 
+/// Borsh encodes the consensus state by way of its protobuf encoding, so that
+/// decoding goes through the same validation as protobuf.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ConsensusState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawConsensusState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ConsensusState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = Vec::<u8>::deserialize_reader(reader)?;
+        Ok(Protobuf::<RawConsensusState>::decode_vec(&bytes)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData)?)
+    }
+}
+
 impl TryFrom<RawConsensusState> for ConsensusState {
     type Error = Error;
 
@@ -132,6 +198,68 @@ impl From<ConsensusState> for Any {
     }
 }
 
+/// A Tendermint consensus state along with its height, which is otherwise only
+/// part of the path it is stored at.
+///
+/// Its protobuf form is the `ConsensusStateWithHeight` of ibc-go, as returned
+/// by consensus state queries, with the consensus state encoded as an [`Any`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusStateWithHeight {
+    pub height: Height,
+    pub consensus_state: ConsensusState,
+}
+
+impl ConsensusStateWithHeight {
+    pub fn new(height: Height, consensus_state: ConsensusState) -> Self {
+        Self {
+            height,
+            consensus_state,
+        }
+    }
+
+    /// Splits this value into the height and the consensus state.
+    pub fn into_parts(self) -> (Height, ConsensusState) {
+        (self.height, self.consensus_state)
+    }
+}
+
+impl Protobuf<RawConsensusStateWithHeight> for ConsensusStateWithHeight {}
+
+impl TryFrom<RawConsensusStateWithHeight> for ConsensusStateWithHeight {
+    type Error = ClientError;
+
+    fn try_from(raw: RawConsensusStateWithHeight) -> Result<Self, Self::Error> {
+        let height: Height = raw
+            .height
+            .ok_or(Error::InvalidRawConsensusState {
+                reason: "missing height in `ConsensusStateWithHeight`".into(),
+            })?
+            .try_into()?;
+
+        let consensus_state: ConsensusState = raw
+            .consensus_state
+            .ok_or(Error::InvalidRawConsensusState {
+                reason: "missing consensus state in `ConsensusStateWithHeight`".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self {
+            height,
+            consensus_state,
+        })
+    }
+}
+
+impl From<ConsensusStateWithHeight> for RawConsensusStateWithHeight {
+    fn from(value: ConsensusStateWithHeight) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
 impl From<tendermint::block::Header> for ConsensusState {
     fn from(header: tendermint::block::Header) -> Self {
         Self {
@@ -149,9 +277,10 @@ impl From<Header> for ConsensusState {
 }
 
 #[cfg(all(test, feature = "serde"))]
-mod tests {
+mod serde_tests {
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
 
+    use super::*;
     use crate::serde_tests::test_serialization_roundtrip;
 
     #[test]
@@ -172,3 +301,65 @@ mod tests {
         test_serialization_roundtrip::<AbciQuery>(json_data);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consensus_state_with_height_roundtrip() {
+        let consensus_state = ConsensusState::new(
+            CommitmentRoot::from_bytes(b"root"),
+            Time::from_unix_timestamp(1_700_000_000, 0).expect("valid time"),
+            Hash::Sha256([1; 32]),
+        );
+        let height = Height::new(1, 42).expect("valid height");
+
+        let with_height = consensus_state.clone().with_height(height);
+
+        let raw = RawConsensusStateWithHeight::from(with_height.clone());
+        assert_eq!(
+            raw.consensus_state,
+            Some(Any::from(consensus_state.clone()))
+        );
+
+        let decoded = ConsensusStateWithHeight::try_from(raw).expect("valid raw value");
+        assert_eq!(decoded, with_height);
+
+        let bytes = Protobuf::<RawConsensusStateWithHeight>::encode_vec(with_height.clone());
+        let decoded: ConsensusStateWithHeight =
+            Protobuf::<RawConsensusStateWithHeight>::decode_vec(&bytes).expect("valid bytes");
+        assert_eq!(decoded, with_height);
+
+        assert_eq!(decoded.into_parts(), (height, consensus_state));
+    }
+
+    #[test]
+    fn consensus_state_with_height_missing_fields() {
+        let raw = RawConsensusStateWithHeight::from(
+            ConsensusState::new(
+                CommitmentRoot::from_bytes(b"root"),
+                Time::from_unix_timestamp(1_700_000_000, 0).expect("valid time"),
+                Hash::Sha256([1; 32]),
+            )
+            .with_height(Height::new(1, 42).expect("valid height")),
+        );
+
+        for raw in [
+            RawConsensusStateWithHeight {
+                height: None,
+                ..raw.clone()
+            },
+            RawConsensusStateWithHeight {
+                consensus_state: None,
+                ..raw
+            },
+        ] {
+            let err = ConsensusStateWithHeight::try_from(raw).expect_err("missing field");
+            assert!(
+                err.to_string().contains("ConsensusStateWithHeight"),
+                "{err}"
+            );
+        }
+    }
+}
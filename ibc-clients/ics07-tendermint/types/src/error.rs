@@ -7,8 +7,9 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 use tendermint::{Error as TendermintError, Hash};
 use tendermint_light_client_verifier::errors::VerificationErrorDetail as LightClientErrorDetail;
 use tendermint_light_client_verifier::operations::VotingPowerTally;
@@ -40,16 +41,26 @@ pub enum Error {
     InvalidProofSpec(CommitmentError),
     /// invalid raw client state: `{reason}`
     InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
     /// missing validator set
     MissingValidatorSet,
     /// missing trusted next validator set
     MissingTrustedNextValidatorSet,
     /// missing trusted height
     MissingTrustedHeight,
+    /// missing trust level
+    MissingTrustLevel,
     /// missing trusting period
     MissingTrustingPeriod,
+    /// invalid trusting period: negative or out of range
+    InvalidTrustingPeriod,
     /// missing unbonding period
     MissingUnbondingPeriod,
+    /// invalid unbonding period: negative or out of range
+    InvalidUnbondingPeriod,
+    /// missing max clock drift
+    MissingMaxClockDrift,
     /// negative max clock drift
     NegativeMaxClockDrift,
     /// missing latest height
@@ -73,6 +84,8 @@ pub enum Error {
     },
     /// the given chain-id (`{given}`) does not match the chain-id of the client (`{expected}`)
     MismatchHeaderChainId { given: String, expected: String },
+    /// the chain-id of the client (`{chain_id}`) is not in the allow-list
+    ChainIdNotAllowed { chain_id: String },
     /// not enough trust because insufficient validators overlap: `{reason}`
     NotEnoughTrustedValsSigned { reason: VotingPowerTally },
     /// verification failed: `{detail}`
@@ -89,12 +102,63 @@ pub enum Error {
         duration_since_consensus_state: Duration,
         trusting_period: Duration,
     },
+    /// cannot revive the client: `{elapsed:?}` elapsed since the trusted consensus state, which is not less than the unbonding period `{unbonding_period:?}`
+    RevivalPastUnbondingPeriod {
+        elapsed: Duration,
+        unbonding_period: Duration,
+    },
+    /// client `{client_id}` is expired and `allow_update.after_expiry` is not set; it can only be recovered through governance (`MsgRecoverClient`)
+    ExpiredClientUpdateNotAllowed { client_id: ClientId },
+    /// consensus state timestamp `{timestamp}` is too far in the future: given the host timestamp and max clock drift, it should be at most `{max}`
+    ConsensusStateTimestampTooFarInFuture {
+        timestamp: Timestamp,
+        max: Timestamp,
+    },
+    /// consensus state at height `{height}` conflicts with the one stored for client `{client_id}`
+    ConflictingConsensusState { client_id: ClientId, height: Height },
+    /// encoded `{type_url}` of `{actual}` bytes exceeds the maximum of `{max}` bytes
+    EncodedMessageTooLarge {
+        type_url: String,
+        actual: usize,
+        max: usize,
+    },
+    /// header validator set of `{actual}` validators exceeds the maximum of `{max}` validators
+    TooManyValidators { actual: usize, max: usize },
+    /// invalid upgraded consensus state: `{reason}`
+    InvalidUpgradedConsensusState { reason: String },
+    /// client id `{client_id}` does not embed the client type `{client_type}`
+    MismatchClientIdType {
+        client_id: ClientId,
+        client_type: ClientType,
+    },
+    /// deprecated `allow_update` flags are set (after expiry: `{after_expiry}`, after misbehaviour: `{after_misbehaviour}`); clients are recovered through governance (`MsgRecoverClient`) instead
+    DeprecatedAllowUpdateSet {
+        after_expiry: bool,
+        after_misbehaviour: bool,
+    },
     /// headers block hashes are equal
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
     MisbehaviourHeadersNotAtSameHeight,
 }
 
+/// The inconsistencies reported when auditing the consensus state history of
+/// a Tendermint client
+#[derive(Debug, Display)]
+pub enum AuditError {
+    /// failed to read the consensus state history: `{0}`
+    Client(ClientError),
+    /// consensus state at height `{height}` has timestamp `{timestamp}`, which is not after timestamp `{previous_timestamp}` of the consensus state at height `{previous_height}`
+    NonMonotonicTimestamp {
+        previous_height: Height,
+        previous_timestamp: Timestamp,
+        height: Height,
+        timestamp: Timestamp,
+    },
+    /// consensus state at height `{height}` is of a revision above that of chain `{chain_id}`
+    RevisionMismatch { height: Height, chain_id: String },
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -108,6 +172,22 @@ impl std::error::Error for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for AuditError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Client(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ClientError> for AuditError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
 impl From<Error> for ClientError {
     fn from(e: Error) -> Self {
         Self::ClientSpecific {
@@ -47,18 +47,52 @@ impl Display for Header {
 }
 
 impl Header {
+    /// Returns the block time of the signed header as an IBC [`Timestamp`].
     pub fn timestamp(&self) -> Timestamp {
         self.signed_header.header.time.into()
     }
 
     pub fn height(&self) -> Height {
-        Height::new(
-            ChainId::from_str(self.signed_header.header.chain_id.as_str())
-                .expect("chain id")
-                .revision_number(),
-            u64::from(self.signed_header.header.height),
-        )
-        .expect("malformed tendermint header domain type has an illegal height of 0")
+        let chain_id =
+            ChainId::from_str(self.signed_header.header.chain_id.as_str()).expect("chain id");
+
+        self.to_height(&chain_id)
+            .expect("malformed tendermint header domain type has an illegal height of 0")
+    }
+
+    /// Returns the IBC height of this header on the chain identified by
+    /// `chain_id`, whose revision number is taken from `chain_id`.
+    ///
+    /// Fails if the header does not belong to `chain_id` or has a height of 0.
+    pub fn to_height(&self, chain_id: &ChainId) -> Result<Height, Error> {
+        if self.signed_header.header.chain_id.as_str() != chain_id.as_str() {
+            return Err(Error::MismatchHeaderChainId {
+                given: self.signed_header.header.chain_id.to_string(),
+                expected: chain_id.to_string(),
+            });
+        }
+
+        let height = u64::from(self.signed_header.header.height);
+
+        Height::new(chain_id.revision_number(), height)
+            .map_err(|_| Error::InvalidHeaderHeight { height })
+    }
+
+    /// Returns the validator set that signed this header.
+    ///
+    /// It is supplied by the relayer, and thus only to be relied upon once
+    /// checked against the validators hash of the signed header.
+    pub fn validator_set(&self) -> &ValidatorSet {
+        &self.validator_set
+    }
+
+    /// Returns the next validator set at the trusted height of this header.
+    ///
+    /// It is supplied by the relayer, and thus only to be relied upon once
+    /// checked against the hash stored on chain, see
+    /// [`Header::check_trusted_next_validator_set`].
+    pub fn trusted_next_validator_set(&self) -> &ValidatorSet {
+        &self.trusted_next_validator_set
     }
 
     pub fn as_untrusted_block_state(&self) -> UntrustedBlockState<'_> {
@@ -118,6 +152,25 @@ impl Header {
         }
     }
 
+    /// Checks that neither the validator set nor the trusted next validator
+    /// set of this header has more than `max` validators.
+    ///
+    /// Verifying a header costs roughly one signature check per validator, so
+    /// this is meant to reject oversized headers before verifying them.
+    pub fn validate_validator_set_size(&self, max: usize) -> Result<(), Error> {
+        let actual = self
+            .validator_set
+            .validators()
+            .len()
+            .max(self.trusted_next_validator_set.validators().len());
+
+        if actual > max {
+            return Err(Error::TooManyValidators { actual, max });
+        }
+
+        Ok(())
+    }
+
     /// Checks if the fields of a given header are consistent with the trusted fields of this header.
     pub fn validate_basic<H: MerkleHash + Sha256 + Default>(&self) -> Result<(), Error> {
         if self.height().revision_number() != self.trusted_height.revision_number() {
@@ -150,6 +203,12 @@ impl Header {
     }
 }
 
+impl From<&Header> for (Height, Timestamp) {
+    fn from(header: &Header) -> Self {
+        (header.height(), header.timestamp())
+    }
+}
+
 impl Protobuf<RawHeader> for Header {}
 
 impl TryFrom<RawHeader> for Header {
@@ -206,6 +265,25 @@ impl TryFrom<Any> for Header {
     }
 }
 
+impl Header {
+    /// Decodes a header from an untrusted [`Any`], rejecting it before
+    /// decoding if its encoded value is larger than `max_bytes`.
+    ///
+    /// Trusted inputs can keep using the unbounded `TryFrom<Any>`.
+    pub fn decode_with_limit(any: &Any, max_bytes: usize) -> Result<Self, ClientError> {
+        if any.value.len() > max_bytes {
+            return Err(Error::EncodedMessageTooLarge {
+                type_url: any.type_url.clone(),
+                actual: any.value.len(),
+                max: max_bytes,
+            }
+            .into());
+        }
+
+        Self::try_from(any.clone())
+    }
+}
+
 impl From<Header> for Any {
     fn from(header: Header) -> Self {
         Any {
@@ -46,6 +46,40 @@ pub fn client_type() -> ClientType {
     ClientType::from_str(TENDERMINT_CLIENT_TYPE).expect("Never fails because it's valid")
 }
 
+/// The ICS-07 Tendermint types that are encoded as `Any`, allowing
+/// integrations to refer to their type URLs without hardcoding strings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Ics07Type {
+    ClientState,
+    ConsensusState,
+    Header,
+    Misbehaviour,
+}
+
+impl Ics07Type {
+    pub const ALL: [Self; 4] = [
+        Self::ClientState,
+        Self::ConsensusState,
+        Self::Header,
+        Self::Misbehaviour,
+    ];
+
+    /// Returns the `Any` type URL of this type.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            Self::ClientState => TENDERMINT_CLIENT_STATE_TYPE_URL,
+            Self::ConsensusState => TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+            Self::Header => TENDERMINT_HEADER_TYPE_URL,
+            Self::Misbehaviour => TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+        }
+    }
+
+    /// Returns the type identified by the given `Any` type URL, if any.
+    pub fn from_type_url(type_url: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.type_url() == type_url)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +89,33 @@ mod tests {
     pub fn test_tm_client_type() {
         let _ = ClientType::from_str(TENDERMINT_CLIENT_TYPE).unwrap();
     }
+
+    #[test]
+    fn test_ics07_type_urls() {
+        let expected = [
+            (
+                Ics07Type::ClientState,
+                "/ibc.lightclients.tendermint.v1.ClientState",
+            ),
+            (
+                Ics07Type::ConsensusState,
+                "/ibc.lightclients.tendermint.v1.ConsensusState",
+            ),
+            (Ics07Type::Header, "/ibc.lightclients.tendermint.v1.Header"),
+            (
+                Ics07Type::Misbehaviour,
+                "/ibc.lightclients.tendermint.v1.Misbehaviour",
+            ),
+        ];
+
+        for (ty, type_url) in expected {
+            assert_eq!(ty.type_url(), type_url);
+            assert_eq!(Ics07Type::from_type_url(type_url), Some(ty));
+        }
+
+        assert_eq!(
+            Ics07Type::from_type_url("/ibc.lightclients.wasm.v1.ClientState"),
+            None
+        );
+    }
 }
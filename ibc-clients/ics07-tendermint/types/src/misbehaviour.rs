@@ -24,12 +24,48 @@ pub struct Misbehaviour {
 }
 
 impl Misbehaviour {
-    pub fn new(client_id: ClientId, header1: Header, header2: Header) -> Self {
-        Self {
+    /// Constructs a new `Misbehaviour` from two conflicting headers.
+    ///
+    /// Both headers must belong to the same chain and must actually be in
+    /// conflict: either they are at the same height but commit to different
+    /// blocks, or `header1` is at a greater height than `header2` while not
+    /// having a later block time (a violation of monotonic time).
+    pub fn new(client_id: ClientId, header1: Header, header2: Header) -> Result<Self, Error> {
+        if header1.signed_header.header.chain_id != header2.signed_header.header.chain_id {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "headers must have identical chain_ids".to_owned(),
+            });
+        }
+
+        if header1.height() == header2.height() {
+            if header1.signed_header.commit.block_id.hash
+                == header2.signed_header.commit.block_id.hash
+            {
+                return Err(Error::MisbehaviourHeadersBlockHashesEqual);
+            }
+        } else if header1.height() < header2.height() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: format!(
+                    "header1 height is less than header2 height ({} < {})",
+                    header1.height(),
+                    header2.height()
+                ),
+            });
+        } else if header1.signed_header.header.time > header2.signed_header.header.time {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: format!(
+                    "header1 height is greater than header2 height but header1 time is later than header2 time ({} > {})",
+                    header1.signed_header.header.time,
+                    header2.signed_header.header.time
+                ),
+            });
+        }
+
+        Ok(Self {
             client_id,
             header1: Box::new(header1),
             header2: Box::new(header2),
-        }
+        })
     }
 
     pub fn client_id(&self) -> &ClientId {
@@ -44,6 +80,12 @@ impl Misbehaviour {
         &self.header2
     }
 
+    /// Wraps the misbehaviour into an [`Any`], e.g. to submit it as the client
+    /// message of a `MsgUpdateClient`.
+    pub fn to_any(self) -> Any {
+        self.into()
+    }
+
     pub fn validate_basic<H: MerkleHash + Sha256 + Default>(&self) -> Result<(), Error> {
         self.header1.validate_basic::<H>()?;
         self.header2.validate_basic::<H>()?;
@@ -91,7 +133,11 @@ impl TryFrom<RawMisbehaviour> for Misbehaviour {
             })?
             .try_into()?;
 
-        Ok(Self::new(client_id, header1, header2))
+        Ok(Self {
+            client_id,
+            header1: Box::new(header1),
+            header2: Box::new(header2),
+        })
     }
 }
 
@@ -2,6 +2,7 @@
 //! represented as a fraction with valid values in the
 //! range `[0, 1)`.
 
+use core::cmp::Ordering;
 use core::fmt::{Display, Error as FmtError, Formatter};
 
 use ibc_core_client_types::error::ClientError;
@@ -87,6 +88,45 @@ impl TrustThreshold {
     pub fn denominator(&self) -> u64 {
         self.denominator
     }
+
+    /// The value of the fraction underlying this trust threshold, e.g. for
+    /// display purposes.
+    ///
+    /// [`TrustThreshold::ZERO`], whose denominator is 0, has a value of 0.
+    pub fn as_f64(&self) -> f64 {
+        if self.denominator == 0 {
+            return 0.0;
+        }
+
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// The value of this trust threshold as a percentage, e.g. `33.33...` for
+    /// [`TrustThreshold::ONE_THIRD`].
+    pub fn percent(&self) -> f64 {
+        self.as_f64() * 100.0
+    }
+
+    /// Compares this trust threshold with `other` by the value of their
+    /// fractions, e.g. 1/4 is less than 1/3.
+    ///
+    /// Unlike [`PartialEq`], which compares the terms that get encoded, this
+    /// treats equal fractions written in distinct terms, e.g. 1/3 and 2/6, as
+    /// equal. [`TrustThreshold::ZERO`] has a value of 0.
+    pub fn cmp_value(&self, other: &Self) -> Ordering {
+        // A zero denominator only occurs in `TrustThreshold::ZERO`, i.e. 0/0,
+        // whose value is zero. The products cannot overflow a `u128`.
+        let lhs = u128::from(self.numerator) * u128::from(other.denominator.max(1));
+        let rhs = u128::from(other.numerator) * u128::from(self.denominator.max(1));
+
+        lhs.cmp(&rhs)
+    }
+
+    /// Whether this trust threshold has the same value as `other`, see
+    /// [`TrustThreshold::cmp_value`].
+    pub fn value_eq(&self, other: &Self) -> bool {
+        self.cmp_value(other) == Ordering::Equal
+    }
 }
 
 /// Conversion from Tendermint domain type into
@@ -139,3 +179,62 @@ impl Display for TrustThreshold {
         write!(f, "{}/{}", self.numerator, self.denominator)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trust_threshold_value_ordering() {
+        let one_quarter = TrustThreshold::new(1, 4).expect("Never fails");
+        let one_half = TrustThreshold::new(1, 2).expect("Never fails");
+
+        assert_eq!(TrustThreshold::ZERO.cmp_value(&one_quarter), Ordering::Less);
+        assert_eq!(
+            one_quarter.cmp_value(&TrustThreshold::ONE_THIRD),
+            Ordering::Less
+        );
+        assert_eq!(
+            TrustThreshold::ONE_THIRD.cmp_value(&one_half),
+            Ordering::Less
+        );
+        assert_eq!(
+            TrustThreshold::TWO_THIRDS.cmp_value(&one_half),
+            Ordering::Greater
+        );
+        assert_eq!(
+            TrustThreshold::new(u64::MAX - 1, u64::MAX)
+                .expect("Never fails")
+                .cmp_value(&TrustThreshold::new(u64::MAX - 2, u64::MAX - 1).expect("Never fails")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn trust_threshold_value_eq() {
+        let two_sixths = TrustThreshold::new(2, 6).expect("Never fails");
+        let zero_fifths = TrustThreshold::new(0, 5).expect("Never fails");
+
+        // Equal values in distinct terms are encoded differently, so they
+        // are only equal by value.
+        assert!(two_sixths.value_eq(&TrustThreshold::ONE_THIRD));
+        assert_ne!(two_sixths, TrustThreshold::ONE_THIRD);
+        assert!(zero_fifths.value_eq(&TrustThreshold::ZERO));
+        assert_ne!(zero_fifths, TrustThreshold::ZERO);
+        assert!(!TrustThreshold::ONE_THIRD.value_eq(&TrustThreshold::TWO_THIRDS));
+    }
+
+    #[test]
+    fn trust_threshold_percent() {
+        let two_thirds = TrustThreshold::new(2, 3).expect("Never fails");
+
+        assert!((TrustThreshold::ONE_THIRD.percent() - 33.33).abs() < 0.01);
+        assert!((two_thirds.percent() - 66.67).abs() < 0.01);
+        assert_eq!(two_thirds.as_f64(), TrustThreshold::TWO_THIRDS.as_f64());
+        assert_eq!(TrustThreshold::ZERO.percent(), 0.0);
+        assert_eq!(
+            TrustThreshold::new(1, 4).expect("Never fails").percent(),
+            25.0
+        );
+    }
+}
@@ -1,18 +1,47 @@
 //! Defines `ClientState`, the core type to be implemented by light clients
 
+use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::{Height, Status};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
-use ibc_core_host_types::identifiers::{ClientId, ClientType};
-use ibc_core_host_types::path::Path;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_host_types::identifiers::{
+    ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
+};
+use ibc_core_host_types::path::{
+    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
+    Path, ReceiptPath, SeqRecvPath,
+};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Any;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::ToVec;
 
 use crate::context::{ClientExecutionContext, ClientValidationContext};
 use crate::Convertible;
 
+/// The operations a light client implementation supports, as reported by
+/// [`ClientStateCommon::capabilities`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClientCapabilities {
+    /// Whether misbehaviour can be submitted to the client.
+    pub supports_misbehaviour: bool,
+    /// Whether the client can be upgraded.
+    pub supports_upgrade: bool,
+    /// Whether the client can verify (non-)membership proofs.
+    pub supports_membership: bool,
+}
+
+impl ClientCapabilities {
+    /// Capabilities of a client supporting all operations.
+    pub const ALL: Self = Self {
+        supports_misbehaviour: true,
+        supports_upgrade: true,
+        supports_membership: true,
+    };
+}
+
 /// `ClientState` methods needed in both validation and execution.
 ///
 /// They do not require access to a client `ValidationContext` nor
@@ -33,6 +62,13 @@ pub trait ClientStateCommon: Convertible<Any> {
     /// Validate that the client is at a sufficient height
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError>;
 
+    /// The operations this client supports, so that generic code, e.g. in
+    /// hosts with multiple light clients, can avoid attempting unsupported
+    /// ones. Defaults to all operations.
+    fn capabilities(&self) -> ClientCapabilities {
+        ClientCapabilities::ALL
+    }
+
     /// Verify the upgraded client and consensus states and validate proofs
     /// against the given root.
     ///
@@ -72,6 +108,176 @@ pub trait ClientStateCommon: Convertible<Any> {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError>;
+
+    /// Verifies a proof that the counterparty stores the `expected_channel_end`
+    /// under the [`ChannelEndPath`] of the given port and channel identifiers.
+    ///
+    /// Builds the path, encodes the expected value and delegates to
+    /// [`verify_membership`](ClientStateCommon::verify_membership).
+    fn verify_channel_state(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::ChannelEnd(ChannelEndPath::new(port_id, channel_id)),
+            expected_channel_end.clone().encode_vec(),
+        )
+    }
+
+    /// Verifies a proof that the counterparty stores the
+    /// `expected_connection_end` under the [`ConnectionPath`] of the given
+    /// connection identifier.
+    ///
+    /// Builds the path, encodes the expected value and delegates to
+    /// [`verify_membership`](ClientStateCommon::verify_membership).
+    fn verify_connection_state(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::Connection(ConnectionPath::new(connection_id)),
+            expected_connection_end.clone().encode_vec(),
+        )
+    }
+
+    /// Verifies a proof that the counterparty stores the
+    /// `expected_client_state` under the [`ClientStatePath`] of the given
+    /// client identifier.
+    ///
+    /// Builds the path, encodes the expected value and delegates to
+    /// [`verify_membership`](ClientStateCommon::verify_membership).
+    fn verify_client_state(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        client_id: &ClientId,
+        expected_client_state: Any,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::ClientState(ClientStatePath::new(client_id.clone())),
+            expected_client_state.to_vec(),
+        )
+    }
+
+    /// Verifies a proof that the counterparty stores the
+    /// `expected_consensus_state` under the [`ClientConsensusStatePath`] of
+    /// the given client identifier at `consensus_height`.
+    ///
+    /// Builds the path, encodes the expected value and delegates to
+    /// [`verify_membership`](ClientStateCommon::verify_membership).
+    fn verify_client_consensus_state(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::ClientConsensusState(consensus_height.consensus_state_path(client_id)),
+            expected_consensus_state.to_vec(),
+        )
+    }
+
+    /// Verifies a proof that the counterparty stores `commitment` under the
+    /// [`CommitmentPath`] of the given port, channel and sequence.
+    ///
+    /// `commitment` is expected to be the packet commitment recomputed from
+    /// the packet, e.g. with `compute_packet_commitment`. Builds the path and
+    /// delegates to [`verify_membership`](ClientStateCommon::verify_membership).
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_commitment(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::Commitment(CommitmentPath::new(port_id, channel_id, sequence)),
+            commitment,
+        )
+    }
+
+    /// Verifies a proof that the counterparty has no packet receipt stored
+    /// under the [`ReceiptPath`] of the given port, channel and sequence.
+    ///
+    /// Builds the path and delegates to
+    /// [`verify_non_membership`](ClientStateCommon::verify_non_membership).
+    ///
+    /// Note that receipts are only written on unordered channels. Ordered
+    /// channels instead prove that a packet was not received by verifying the
+    /// membership of the next receive sequence under the `SeqRecvPath`, see
+    /// [`verify_next_sequence_recv`](ClientStateCommon::verify_next_sequence_recv).
+    fn verify_packet_receipt_absence(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), ClientError> {
+        self.verify_non_membership(
+            prefix,
+            proof,
+            root,
+            Path::Receipt(ReceiptPath::new(port_id, channel_id, sequence)),
+        )
+    }
+
+    /// Verifies a proof that the counterparty stores `sequence` under the
+    /// [`SeqRecvPath`] of the given port and channel identifiers.
+    ///
+    /// Builds the path, encodes the sequence as a big-endian `u64`, as the
+    /// Cosmos SDK stores it, and delegates to
+    /// [`verify_membership`](ClientStateCommon::verify_membership).
+    fn verify_next_sequence_recv(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(
+            prefix,
+            proof,
+            root,
+            Path::SeqRecv(SeqRecvPath::new(port_id, channel_id)),
+            sequence.to_vec(),
+        )
+    }
 }
 
 /// `ClientState` methods which require access to the client's validation
@@ -122,6 +328,16 @@ where
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
 
+    /// Checks whether the client is allowed to process a client message
+    /// (i.e. a header or misbehaviour) given its current status.
+    ///
+    /// By default, only `Active` clients can be updated. Light clients that
+    /// support reviving expired clients through regular updates can override
+    /// this method.
+    fn check_update_allowed(&self, ctx: &V, client_id: &ClientId) -> Result<(), ClientError> {
+        self.status(ctx, client_id)?.verify_is_active()
+    }
+
     /// Verifies whether the calling (subject) client state matches the substitute
     /// client state for the purposes of client recovery.
     ///
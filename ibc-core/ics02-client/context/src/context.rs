@@ -1,4 +1,5 @@
-use ibc_core_client_types::Height;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::{Height, Status};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_core_host_types::path::{ClientConsensusStatePath, ClientStatePath};
@@ -21,6 +22,16 @@ pub trait ClientValidationContext: Sized {
     /// Note: Clients have the responsibility to store client states on client creation and update.
     fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError>;
 
+    /// Returns whether a client state is stored for the given identifier
+    /// `client_id`.
+    ///
+    /// The default implementation fetches and decodes the whole client state.
+    /// Hosts able to check for the presence of a key more cheaply should
+    /// override it.
+    fn client_exists(&self, client_id: &ClientId) -> bool {
+        self.client_state(client_id).is_ok()
+    }
+
     /// Retrieve the consensus state for the given client ID at the specified
     /// height.
     ///
@@ -39,6 +50,75 @@ pub trait ClientValidationContext: Sized {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<(Timestamp, Height), ContextError>;
+
+    /// Returns the identifiers of all the clients stored on the host, e.g. to
+    /// enumerate them and report their statuses.
+    ///
+    /// Note: Hosts backed by key-value stores that cannot be iterated over may
+    /// keep this default implementation, which returns an error.
+    fn clients(&self) -> Result<Vec<ClientId>, ContextError> {
+        Err(ClientError::Other {
+            description: "enumerating clients is not supported by this host".to_string(),
+        }
+        .into())
+    }
+
+    /// Returns the height of the consensus state the client identified by
+    /// `client_id` was created with, i.e. its latest height at creation.
+    ///
+    /// Unlike that consensus state, which may since have been pruned, the
+    /// height is retained for the lifetime of the client by hosts recording it
+    /// in [`ClientExecutionContext::store_genesis_consensus_height`]. The
+    /// default implementation returns `None`.
+    fn genesis_consensus_height(
+        &self,
+        _client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns `true` if the client identified by `client_id` may only be
+    /// updated with headers adjacent to their trusted height, i.e. without
+    /// skipping verification.
+    ///
+    /// The default implementation returns `false`, so that light clients
+    /// supporting skipping verification may use it.
+    fn requires_adjacent_updates(&self, _client_id: &ClientId) -> bool {
+        false
+    }
+
+    /// Returns `true` if the client identified by `client_id` has been paused
+    /// through [`ClientExecutionContext::pause_client`].
+    ///
+    /// A paused client rejects updates but is otherwise unaffected: unlike a
+    /// frozen client, its status remains `Active`. The default implementation
+    /// returns `false`.
+    fn is_client_paused(&self, _client_id: &ClientId) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+}
+
+/// Computes the status of each of the clients identified by `client_ids`,
+/// e.g. those returned by [`ClientValidationContext::clients`].
+///
+/// Errors are collected per client, so that a missing client or one whose
+/// status cannot be computed does not fail the whole batch. The statuses are
+/// returned in the order of `client_ids`.
+pub fn statuses<V>(ctx: &V, client_ids: &[ClientId]) -> Vec<(ClientId, Result<Status, ClientError>)>
+where
+    V: ClientValidationContext,
+{
+    client_ids
+        .iter()
+        .map(|client_id| {
+            let status = ctx
+                .client_state(client_id)
+                .map_err(ClientError::from)
+                .and_then(|client_state| client_state.status(ctx, client_id));
+
+            (client_id.clone(), status)
+        })
+        .collect()
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -102,6 +182,138 @@ pub trait ClientExecutionContext:
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Called upon successful client creation with the latest height of the
+    /// created client, at which its initial consensus state is stored.
+    ///
+    /// Hosts can override this hook to serve
+    /// [`ClientValidationContext::genesis_consensus_height`]. The default
+    /// implementation does nothing.
+    fn store_genesis_consensus_height(
+        &mut self,
+        _client_id: &ClientId,
+        _height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Pauses the client identified by `client_id`, e.g. while an operator
+    /// investigates it, so that it rejects updates until
+    /// [`ClientExecutionContext::unpause_client`] is called.
+    ///
+    /// The default implementation returns an error, as the host does not
+    /// record paused clients.
+    fn pause_client(&mut self, _client_id: &ClientId) -> Result<(), ContextError> {
+        Err(ClientError::Other {
+            description: "pausing clients is not supported by this host".to_string(),
+        }
+        .into())
+    }
+
+    /// Resumes updates for the client identified by `client_id` after it was
+    /// paused with [`ClientExecutionContext::pause_client`].
+    ///
+    /// The default implementation returns an error, as the host does not
+    /// record paused clients.
+    fn unpause_client(&mut self, _client_id: &ClientId) -> Result<(), ContextError> {
+        Err(ClientError::Other {
+            description: "pausing clients is not supported by this host".to_string(),
+        }
+        .into())
+    }
+
+    /// Called after the client identified by `client_id` has been
+    /// successfully updated to a consensus state at `height`.
+    ///
+    /// Hosts can override this hook, for instance, to feed a metrics system.
+    /// The default implementation does nothing.
+    fn on_client_updated(
+        &mut self,
+        _client_id: &ClientId,
+        _height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called after misbehaviour has been detected for the client identified
+    /// by `client_id` and the client has been frozen.
+    ///
+    /// Hosts can override this hook, for instance, to feed a metrics system.
+    /// The default implementation does nothing.
+    fn on_misbehaviour_detected(&mut self, _client_id: &ClientId) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called after the consensus state of the client identified by
+    /// `client_id` at `height` has been pruned, along with its update
+    /// metadata.
+    ///
+    /// Hosts can override this hook, for instance, to keep a secondary index
+    /// of consensus states consistent. The default implementation does
+    /// nothing.
+    fn on_consensus_state_pruned(
+        &mut self,
+        _client_id: &ClientId,
+        _height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Returns `true` if light clients must only be initialised under
+    /// client identifiers embedding their client type, as the ones created by
+    /// the create-client handler do.
+    ///
+    /// Hosts only creating clients through the create-client handler can
+    /// override this to return `true`. The default implementation returns
+    /// `false`, which accepts any client identifier, e.g. a contract address.
+    fn requires_typed_client_ids(&self) -> bool {
+        false
+    }
+
+    /// Returns the policy consulted when pruning the consensus states of a
+    /// client, if the host has one.
+    ///
+    /// Without a policy, i.e. by default, light clients prune exactly the
+    /// consensus states which have expired.
+    fn consensus_state_cache_policy(&self) -> Option<&dyn ConsensusStateCachePolicy> {
+        None
+    }
+}
+
+/// Metadata about a stored consensus state, supplied to a
+/// [`ConsensusStateCachePolicy`] when the consensus states of a client are
+/// pruned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusStateMeta {
+    /// The timestamp of the consensus state.
+    pub timestamp: Timestamp,
+    /// Whether the consensus state has outlived the trusting period of its
+    /// client as of the host timestamp.
+    pub is_expired: bool,
+}
+
+/// A host-side policy deciding which consensus states are evicted when the
+/// consensus states of a client are pruned, e.g. to retain the ones most
+/// frequently referenced for packet verification beyond their expiry.
+///
+/// Each evicted consensus state is reported through
+/// [`ClientExecutionContext::on_consensus_state_pruned`]. Note that a policy
+/// evicting the consensus state at the latest height of a client leaves the
+/// client without a status.
+pub trait ConsensusStateCachePolicy {
+    /// Returns `true` if the consensus state of the client identified by
+    /// `client_id` at `height` should be evicted.
+    ///
+    /// The default implementation evicts expired consensus states, as is done
+    /// without any policy.
+    fn should_evict(
+        &self,
+        _client_id: &ClientId,
+        _height: Height,
+        meta: &ConsensusStateMeta,
+    ) -> bool {
+        meta.is_expired
+    }
 }
 
 /// An optional trait that extends the client validation context capabilities by
@@ -123,6 +335,19 @@ pub trait ExtClientValidationContext: ClientValidationContext {
     /// Returns all the heights at which a consensus state is stored.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns the lowest height at which a consensus state is stored, or
+    /// `None` if there is none.
+    ///
+    /// The default implementation scans all the heights returned by
+    /// [`Self::consensus_state_heights`]. Hosts with storage ordered by
+    /// height may override it with a cheaper lookup.
+    fn oldest_consensus_state_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(self.consensus_state_heights(client_id)?.into_iter().min())
+    }
+
     /// Search for the lowest consensus state higher than `height`.
     fn next_consensus_state(
         &self,
@@ -43,7 +43,7 @@ where
 
     client_state.verify_consensus_state(consensus_state)?;
 
-    if client_val_ctx.client_state(&client_id).is_ok() {
+    if client_val_ctx.client_exists(&client_id) {
         return Err(ClientError::ClientStateAlreadyExists { client_id }.into());
     };
 
@@ -73,6 +73,8 @@ where
 
     client_state.initialise(client_exec_ctx, &client_id, consensus_state)?;
 
+    client_exec_ctx.store_genesis_consensus_height(&client_id, client_state.latest_height())?;
+
     ctx.increase_client_counter()?;
 
     let event = IbcEvent::CreateClient(CreateClient::new(
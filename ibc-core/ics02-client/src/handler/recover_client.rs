@@ -4,7 +4,6 @@ use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::msgs::MsgRecoverClient;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 
 /// Performs the validation steps associated with the client recovery process. This
@@ -73,12 +72,11 @@ where
 
     let subject_client_state = client_exec_ctx.client_state(&subject_client_id)?;
     let substitute_client_state = client_exec_ctx.client_state(&substitute_client_id)?;
-    let substitute_consensus_state =
-        client_exec_ctx.consensus_state(&ClientConsensusStatePath::new(
-            substitute_client_id.clone(),
-            substitute_client_state.latest_height().revision_number(),
-            substitute_client_state.latest_height().revision_height(),
-        ))?;
+    let substitute_consensus_state = client_exec_ctx.consensus_state(
+        &substitute_client_state
+            .latest_height()
+            .consensus_state_path(&substitute_client_id),
+    )?;
 
     subject_client_state.update_on_recovery(
         ctx.get_client_execution_context(),
@@ -24,9 +24,16 @@ where
     // Read client state from the host chain store. The client should already exist.
     let client_state = client_val_ctx.client_state(&client_id)?;
 
-    client_state
-        .status(client_val_ctx, &client_id)?
-        .verify_is_active()?;
+    client_state.check_update_allowed(client_val_ctx, &client_id)?;
+
+    // A paused client keeps its `Active` status but accepts no updates until
+    // it is unpaused. Misbehaviour submitted as such is still processed, so
+    // that the client can be frozen while paused.
+    if matches!(msg, MsgUpdateOrMisbehaviour::UpdateClient(_))
+        && client_val_ctx.is_client_paused(&client_id)?
+    {
+        return Err(ClientError::ClientPaused { client_id }.into());
+    }
 
     let client_message = msg.client_message();
 
@@ -55,6 +62,7 @@ where
 
     if found_misbehaviour {
         client_state.update_state_on_misbehaviour(client_exec_ctx, &client_id, client_message)?;
+        client_exec_ctx.on_misbehaviour_detected(&client_id)?;
 
         let event = IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
             client_id,
@@ -81,6 +89,8 @@ where
                     description: "client update state returned no updated height".to_string(),
                 })?;
 
+                client_exec_ctx.on_client_updated(&client_id, *consensus_height)?;
+
                 IbcEvent::UpdateClient(UpdateClient::new(
                     client_id,
                     client_state.client_type(),
@@ -6,7 +6,6 @@ use ibc_core_client_types::events::UpgradeClient;
 use ibc_core_client_types::msgs::MsgUpgradeClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 
@@ -31,11 +30,9 @@ where
         .verify_is_active()?;
 
     // Read the latest consensus state from the host chain store.
-    let old_client_cons_state_path = ClientConsensusStatePath::new(
-        client_id.clone(),
-        old_client_state.latest_height().revision_number(),
-        old_client_state.latest_height().revision_height(),
-    );
+    let old_client_cons_state_path = old_client_state
+        .latest_height()
+        .consensus_state_path(&client_id);
     let old_consensus_state = client_val_ctx
         .consensus_state(&old_client_cons_state_path)
         .map_err(|_| ClientError::ConsensusStateNotFound {
@@ -19,6 +19,8 @@ pub enum ClientError {
     Upgrade(UpgradeClientError),
     /// client is frozen with description: `{description}`
     ClientFrozen { description: String },
+    /// client `{client_id}` is paused and does not accept updates
+    ClientPaused { client_id: ClientId },
     /// client is not active. Status=`{status}`
     ClientNotActive { status: Status },
     /// client is not frozen or expired. Status=`{status}`
@@ -40,6 +42,11 @@ pub enum ClientError {
     UpdateMetaDataNotFound { client_id: ClientId, height: Height },
     /// header verification failed with reason: `{reason}`
     HeaderVerificationFailure { reason: String },
+    /// header timestamp `{header_time}` is not after the trusted consensus state timestamp `{trusted_time}`
+    HeaderTimestampTooOld {
+        header_time: Timestamp,
+        trusted_time: Timestamp,
+    },
     /// failed to build trust threshold from fraction: `{numerator}`/`{denominator}`
     InvalidTrustThreshold { numerator: u64, denominator: u64 },
     /// failed to build Tendermint domain type trust threshold from fraction: `{numerator}`/`{denominator}`
@@ -5,6 +5,8 @@ use core::num::ParseIntError;
 use core::str::FromStr;
 
 use displaydoc::Display;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::Protobuf;
@@ -49,6 +51,19 @@ impl Height {
         })
     }
 
+    /// Builds a height from the revision number and revision height returned
+    /// separately by a query, e.g. as `revision_number` and `revision_height`
+    /// request parameters of an ABCI query.
+    ///
+    /// Like [`Height::new`], fails with [`ClientError::InvalidHeight`] if the
+    /// revision height is zero.
+    pub fn from_query_parts(
+        revision_number: u64,
+        revision_height: u64,
+    ) -> Result<Self, ClientError> {
+        Self::new(revision_number, revision_height)
+    }
+
     pub fn min(revision_number: u64) -> Self {
         Self {
             revision_number,
@@ -64,6 +79,19 @@ impl Height {
         self.revision_height
     }
 
+    /// Returns the [`ClientConsensusStatePath`] under which the consensus
+    /// state of the given client at this height is stored.
+    ///
+    /// Prefer this over [`ClientConsensusStatePath::new`], which takes the
+    /// revision number and revision height as separate, easily swapped, `u64`s.
+    pub fn consensus_state_path(&self, client_id: &ClientId) -> ClientConsensusStatePath {
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            self.revision_number,
+            self.revision_height,
+        )
+    }
+
     pub fn add(&self, delta: u64) -> Height {
         Height {
             revision_number: self.revision_number,
@@ -89,6 +117,24 @@ impl Height {
     pub fn decrement(&self) -> Result<Height, ClientError> {
         self.sub(1)
     }
+
+    /// Returns the number of blocks this height is ahead of `other`.
+    ///
+    /// Block counts don't carry over revisions, so this is `None` if the
+    /// revision numbers differ, as well as if `other` is the later height.
+    pub fn blocks_since(&self, other: &Height) -> Option<u64> {
+        if self.revision_number != other.revision_number {
+            return None;
+        }
+
+        self.revision_height.checked_sub(other.revision_height)
+    }
+
+    /// Returns whether this height is at or after `other`, where any height
+    /// of a later revision is after all heights of earlier revisions.
+    pub fn is_at_least(&self, other: &Height) -> bool {
+        self >= other
+    }
 }
 
 impl PartialOrd for Height {
@@ -217,6 +263,30 @@ impl FromStr for Height {
     }
 }
 
+/// Serializes and deserializes a [`Height`] in its `"{revision_number}-{revision_height}"`
+/// string form (e.g. `"1-234"`), as used by several JSON-first ecosystem
+/// payloads. Opt into it by annotating a field with
+/// `#[serde(with = "ibc_core_client_types::height::as_str")]`.
+///
+/// Deserialization accepts exactly one dash separating two numeric parts.
+#[cfg(feature = "serde")]
+pub mod as_str {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::*;
+
+    pub fn serialize<S: Serializer>(height: &Height, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&height.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Height, D::Error> {
+        let height = String::deserialize(deserializer)?;
+
+        Height::from_str(&height).map_err(Error::custom)
+    }
+}
+
 #[test]
 fn test_valid_height() {
     assert_eq!(
@@ -235,6 +305,46 @@ fn test_valid_height() {
     );
 }
 
+#[test]
+fn test_height_from_query_parts() {
+    let height = Height::from_query_parts(4, 21).unwrap();
+    assert_eq!(height.revision_number(), 4);
+    assert_eq!(height.revision_height(), 21);
+
+    assert_eq!(Height::from_query_parts(0, 1).unwrap(), Height::min(0));
+
+    assert!(matches!(
+        Height::from_query_parts(4, 0),
+        Err(ClientError::InvalidHeight)
+    ));
+}
+
+#[test]
+fn test_consensus_state_path_from_height() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let height = Height::new(1, 10).unwrap();
+
+    assert_eq!(
+        height.consensus_state_path(&client_id),
+        ClientConsensusStatePath::new(client_id, 1, 10)
+    );
+}
+
+#[test]
+fn test_height_blocks_since() {
+    let height =
+        |revision_number, revision_height| Height::new(revision_number, revision_height).unwrap();
+
+    assert_eq!(height(1, 10).blocks_since(&height(1, 4)), Some(6));
+    assert_eq!(height(1, 10).blocks_since(&height(1, 10)), Some(0));
+    assert_eq!(height(1, 4).blocks_since(&height(1, 10)), None);
+    assert_eq!(height(2, 10).blocks_since(&height(1, 4)), None);
+
+    assert!(height(1, 10).is_at_least(&height(1, 10)));
+    assert!(height(2, 1).is_at_least(&height(1, 10)));
+    assert!(!height(1, 4).is_at_least(&height(1, 10)));
+}
+
 #[test]
 fn test_invalid_height() {
     assert_eq!(
@@ -258,3 +368,37 @@ fn test_invalid_height() {
         })
     );
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use rstest::rstest;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+    struct Foo {
+        #[serde(with = "as_str")]
+        height: Height,
+    }
+
+    #[test]
+    fn test_height_as_str_roundtrip() {
+        let foo = Foo {
+            height: Height::new(0, 10).unwrap(),
+        };
+        let json = r#"{"height":"0-10"}"#;
+
+        assert_eq!(serde_json::to_string(&foo).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Foo>(json).unwrap(), foo);
+    }
+
+    #[rstest]
+    #[case::no_dash(r#"{"height":"10"}"#)]
+    #[case::two_dashes(r#"{"height":"1-2-3"}"#)]
+    #[case::non_numeric(r#"{"height":"a-1"}"#)]
+    #[case::zero_height(r#"{"height":"1-0"}"#)]
+    fn test_height_as_str_rejects_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<Foo>(json).is_err());
+    }
+}
@@ -16,7 +16,7 @@ extern crate std;
 
 pub mod error;
 pub mod events;
-mod height;
+pub mod height;
 pub mod msgs;
 mod status;
 
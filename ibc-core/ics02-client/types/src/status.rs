@@ -31,6 +31,10 @@ pub enum Status {
     Expired,
     /// Unauthorized indicates that the client type is not registered as an allowed client type.
     Unauthorized,
+    /// Unknown indicates that the status of the client cannot be determined,
+    /// e.g. because there is no consensus state stored for its latest height
+    /// (never initialized or already pruned).
+    Unknown,
 }
 
 impl Status {
@@ -46,6 +50,10 @@ impl Status {
         *self == Status::Expired
     }
 
+    pub fn is_unknown(&self) -> bool {
+        *self == Status::Unknown
+    }
+
     /// Checks whether the status is active; returns `Err` if not.
     pub fn verify_is_active(&self) -> Result<(), ClientError> {
         match self {
@@ -61,26 +69,79 @@ impl Status {
             &status => Err(ClientError::ClientNotInactive { status }),
         }
     }
+
+    /// Returns the stable string representation of the status, e.g. `Active`,
+    /// as used by its `Display` and `serde` implementations.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "Active",
+            Self::Frozen => "Frozen",
+            Self::Expired => "Expired",
+            Self::Unauthorized => "Unauthorized",
+            Self::Unknown => "Unknown",
+        }
+    }
 }
 
 impl Display for Status {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        write!(f, "{}", self.as_str())
     }
 }
 
+/// Parses the string representation of a status, e.g. `Active`, as well as
+/// the upper-case one used by ibc-go, e.g. `ACTIVE`.
 impl FromStr for Status {
     type Err = ClientError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "ACTIVE" => Ok(Status::Active),
-            "FROZEN" => Ok(Status::Frozen),
-            "EXPIRED" => Ok(Status::Expired),
-            "UNAUTHORIZED" => Ok(Status::Unauthorized),
+            "Active" | "ACTIVE" => Ok(Status::Active),
+            "Frozen" | "FROZEN" => Ok(Status::Frozen),
+            "Expired" | "EXPIRED" => Ok(Status::Expired),
+            "Unauthorized" | "UNAUTHORIZED" => Ok(Status::Unauthorized),
+            "Unknown" | "UNKNOWN" => Ok(Status::Unknown),
             _ => Err(ClientError::Other {
                 description: format!("invalid status string: {s}"),
             }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Status::Active, "Active", "ACTIVE")]
+    #[case(Status::Frozen, "Frozen", "FROZEN")]
+    #[case(Status::Expired, "Expired", "EXPIRED")]
+    #[case(Status::Unauthorized, "Unauthorized", "UNAUTHORIZED")]
+    #[case(Status::Unknown, "Unknown", "UNKNOWN")]
+    fn test_status_string_roundtrip(
+        #[case] status: Status,
+        #[case] repr: &str,
+        #[case] ibc_go_repr: &str,
+    ) {
+        assert_eq!(status.to_string(), repr);
+        assert_eq!(Status::from_str(repr).unwrap(), status);
+        assert_eq!(Status::from_str(ibc_go_repr).unwrap(), status);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&status).unwrap();
+            assert_eq!(json, format!("\"{repr}\""));
+            assert_eq!(serde_json::from_str::<Status>(&json).unwrap(), status);
+        }
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("active")]
+    #[case("Paused")]
+    fn test_status_from_str_rejects_unknown_strings(#[case] s: &str) {
+        assert!(Status::from_str(s).is_err());
+    }
+}
@@ -9,7 +9,7 @@ use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ClientId;
-use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
+use ibc_core_host::types::path::{ClientStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
@@ -67,11 +67,9 @@ where
             .verify_is_active()?;
         client_state_of_b_on_a.validate_proof_height(msg.proofs_height_on_b)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            vars.client_id_on_a().clone(),
-            msg.proofs_height_on_b.revision_number(),
-            msg.proofs_height_on_b.revision_height(),
-        );
+        let client_cons_state_path_on_a = msg
+            .proofs_height_on_b
+            .consensus_state_path(vars.client_id_on_a());
 
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
@@ -119,19 +117,14 @@ where
         let expected_consensus_state_of_a_on_b =
             ctx_a.host_consensus_state(&msg.consensus_height_of_a_on_b)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            vars.client_id_on_b().clone(),
-            msg.consensus_height_of_a_on_b.revision_number(),
-            msg.consensus_height_of_a_on_b.revision_height(),
-        );
-
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_client_consensus_state(
                 prefix_on_b,
                 &msg.proof_consensus_state_of_a_on_b,
                 consensus_state_of_b_on_a.root(),
-                Path::ClientConsensusState(client_cons_state_path_on_b),
-                expected_consensus_state_of_a_on_b.into().to_vec(),
+                vars.client_id_on_b(),
+                msg.consensus_height_of_a_on_b,
+                expected_consensus_state_of_a_on_b.into(),
             )
             .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
                 height: msg.proofs_height_on_b,
@@ -8,9 +8,7 @@ use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
-use ibc_core_host::types::path::{
-    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
-};
+use ibc_core_host::types::path::{ClientConnectionPath, ClientStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
@@ -67,11 +65,9 @@ where
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proofs_height_on_a)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            msg.client_id_on_b.clone(),
-            msg.proofs_height_on_a.revision_number(),
-            msg.proofs_height_on_a.revision_height(),
-        );
+        let client_cons_state_path_on_b = msg
+            .proofs_height_on_a
+            .consensus_state_path(&msg.client_id_on_b);
 
         let consensus_state_of_a_on_b =
             client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
@@ -115,19 +111,14 @@ where
         let expected_consensus_state_of_b_on_a =
             ctx_b.host_consensus_state(&msg.consensus_height_of_b_on_a)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.consensus_height_of_b_on_a.revision_number(),
-            msg.consensus_height_of_b_on_a.revision_height(),
-        );
-
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_client_consensus_state(
                 prefix_on_a,
                 &msg.proof_consensus_state_of_b_on_a,
                 consensus_state_of_a_on_b.root(),
-                Path::ClientConsensusState(client_cons_state_path_on_a),
-                expected_consensus_state_of_b_on_a.into().to_vec(),
+                client_id_on_a,
+                msg.consensus_height_of_b_on_a,
+                expected_consensus_state_of_b_on_a.into(),
             )
             .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
                 height: msg.proofs_height_on_a,
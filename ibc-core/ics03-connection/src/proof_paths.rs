@@ -0,0 +1,25 @@
+//! Paths of the counterparty state proven during the connection handshake.
+
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host::types::path::{ClientStatePath, ConnectionPath, Path};
+use ibc_primitives::prelude::*;
+
+/// Returns the paths of the counterparty state whose proofs a `ConnOpenTry` or
+/// `ConnOpenAck` message carries, in the order of the proofs in the message.
+///
+/// These are the paths of the connection end identified by `connection_id`,
+/// and of the client state and the consensus state at `consensus_height` of
+/// the client identified by `client_id`, which tracks the receiving chain.
+/// All identifiers are those of the counterparty.
+pub fn connection_handshake_proof_paths(
+    client_id: &ClientId,
+    connection_id: &ConnectionId,
+    consensus_height: Height,
+) -> Vec<Path> {
+    vec![
+        Path::Connection(ConnectionPath::new(connection_id)),
+        Path::ClientState(ClientStatePath::new(client_id.clone())),
+        Path::ClientConsensusState(consensus_height.consensus_state_path(client_id)),
+    ]
+}
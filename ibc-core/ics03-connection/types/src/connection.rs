@@ -15,7 +15,7 @@ use ibc_proto::ibc::core::connection::v1::{
 use ibc_proto::Protobuf;
 
 use crate::error::ConnectionError;
-use crate::version::Version;
+use crate::version::{pick_version, Version};
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -347,6 +347,21 @@ impl ConnectionEnd {
     pub fn delay_period(&self) -> Duration {
         self.delay_period
     }
+
+    /// Negotiates the version of a connection during the handshake by picking
+    /// a version out of the `proposed` ones that is compatible with the
+    /// `supported` ones. The picked version carries the intersection of the
+    /// features of both sides.
+    ///
+    /// Errors if there is no version in common, or none of the common versions
+    /// share any feature. See [`version::pick_version`](crate::version::pick_version)
+    /// for the exact selection rules.
+    pub fn pick_version(
+        supported: &[Version],
+        proposed: &[Version],
+    ) -> Result<Version, ConnectionError> {
+        pick_version(supported, proposed)
+    }
 }
 
 #[cfg_attr(
@@ -548,3 +563,51 @@ impl From<State> for i32 {
         value as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
+
+    use super::*;
+
+    fn version(identifier: &str, features: &[&str]) -> Version {
+        RawVersion {
+            identifier: identifier.to_string(),
+            features: features.iter().map(|f| f.to_string()).collect(),
+        }
+        .try_into()
+        .expect("valid version")
+    }
+
+    #[test]
+    fn pick_version_exact_match() {
+        let picked =
+            ConnectionEnd::pick_version(&Version::compatibles(), &Version::compatibles()).unwrap();
+
+        assert_eq!(picked, Version::compatibles()[0]);
+    }
+
+    #[test]
+    fn pick_version_feature_subset() {
+        let proposed = vec![version("1", &["ORDER_UNORDERED"])];
+
+        let picked = ConnectionEnd::pick_version(&Version::compatibles(), &proposed).unwrap();
+
+        assert_eq!(picked, version("1", &["ORDER_UNORDERED"]));
+    }
+
+    #[test]
+    fn pick_version_no_overlap() {
+        let no_common_identifier = vec![version("2", &["ORDER_ORDERED"])];
+        let no_common_feature = vec![version("1", &["ORDER_RANDOM"])];
+
+        assert!(matches!(
+            ConnectionEnd::pick_version(&Version::compatibles(), &no_common_identifier),
+            Err(ConnectionError::NoCommonVersion)
+        ));
+        assert!(matches!(
+            ConnectionEnd::pick_version(&Version::compatibles(), &no_common_feature),
+            Err(ConnectionError::NoCommonVersion)
+        ));
+    }
+}
@@ -2,6 +2,7 @@
 
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::packet::DEFAULT_MAX_PACKET_DATA_LEN;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
@@ -26,6 +27,18 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns whether packets with neither a timeout height nor a timeout
+    /// timestamp may be sent.
+    fn allows_packets_without_timeout(&self) -> bool {
+        false
+    }
+
+    /// Returns the maximum length, in bytes, of the data of packets that may
+    /// be sent.
+    fn max_packet_data_len(&self) -> usize {
+        DEFAULT_MAX_PACKET_DATA_LEN
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -52,6 +65,14 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn allows_packets_without_timeout(&self) -> bool {
+        ValidationContext::allows_packets_without_timeout(self)
+    }
+
+    fn max_packet_data_len(&self) -> usize {
+        ValidationContext::max_packet_data_len(self)
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
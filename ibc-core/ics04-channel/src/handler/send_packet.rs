@@ -6,9 +6,7 @@ use ibc_core_channel_types::packet::Packet;
 use ibc_core_client::context::prelude::*;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
@@ -30,10 +28,22 @@ pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
     packet: &Packet,
 ) -> Result<(), ContextError> {
-    if !packet.timeout_height_on_b.is_set() && !packet.timeout_timestamp_on_b.is_set() {
+    if !packet.timeout_height_on_b.is_set()
+        && !packet.timeout_timestamp_on_b.is_set()
+        && !ctx_a.allows_packets_without_timeout()
+    {
         return Err(ContextError::PacketError(PacketError::MissingTimeout));
     }
 
+    let max_packet_data_len = ctx_a.max_packet_data_len();
+    if packet.data.len() > max_packet_data_len {
+        return Err(PacketError::PacketDataTooLarge {
+            len: packet.data.len(),
+            max: max_packet_data_len,
+        }
+        .into());
+    }
+
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -72,11 +82,7 @@ pub fn send_packet_validate(
         .into());
     }
 
-    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-        client_id_on_a.clone(),
-        latest_height_on_a.revision_number(),
-        latest_height_on_a.revision_height(),
-    );
+    let client_cons_state_path_on_a = latest_height_on_a.consensus_state_path(client_id_on_a);
     let consensus_state_of_b_on_a =
         client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
     let latest_timestamp = consensus_state_of_b_on_a.timestamp();
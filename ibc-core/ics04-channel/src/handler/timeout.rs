@@ -7,9 +7,7 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
@@ -188,11 +186,8 @@ where
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
         // check that timeout height or timeout timestamp has passed on the other end
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
+        let client_cons_state_path_on_a =
+            msg.proof_height_on_b.consensus_state_path(client_id_on_a);
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
@@ -218,31 +213,23 @@ where
                     }
                     .into());
                 }
-                let seq_recv_path_on_b =
-                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-
-                client_state_of_b_on_a.verify_membership(
+                client_state_of_b_on_a.verify_next_sequence_recv(
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
-                    Path::SeqRecv(seq_recv_path_on_b),
-                    msg.packet.seq_on_a.to_vec(),
-                )
-            }
-            Order::Unordered => {
-                let receipt_path_on_b = ReceiptPath::new(
                     &msg.packet.port_id_on_b,
                     &msg.packet.chan_id_on_b,
                     msg.packet.seq_on_a,
-                );
-
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::Receipt(receipt_path_on_b),
                 )
             }
+            Order::Unordered => client_state_of_b_on_a.verify_packet_receipt_absence(
+                conn_end_on_a.counterparty().prefix(),
+                &msg.proof_unreceived_on_b,
+                consensus_state_of_b_on_a.root(),
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            ),
             Order::None => {
                 return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
                     expected: "Channel ordering cannot be None".to_string(),
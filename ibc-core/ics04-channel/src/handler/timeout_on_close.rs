@@ -5,9 +5,7 @@ use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, Path};
 use ibc_core_host::ValidationContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
@@ -71,11 +69,8 @@ where
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
+        let client_cons_state_path_on_a =
+            msg.proof_height_on_b.consensus_state_path(client_id_on_a);
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let prefix_on_b = conn_end_on_a.counterparty().prefix();
@@ -128,31 +123,23 @@ where
                     }
                     .into());
                 }
-                let seq_recv_path_on_b =
-                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
-
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::SeqRecv(seq_recv_path_on_b),
-                    packet.seq_on_a.to_vec(),
-                )
-            }
-            Order::Unordered => {
-                let receipt_path_on_b = ReceiptPath::new(
-                    &msg.packet.port_id_on_b,
-                    &msg.packet.chan_id_on_b,
-                    msg.packet.seq_on_a,
-                );
-
-                client_state_of_b_on_a.verify_non_membership(
+                client_state_of_b_on_a.verify_next_sequence_recv(
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
-                    Path::Receipt(receipt_path_on_b),
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    packet.seq_on_a,
                 )
             }
+            Order::Unordered => client_state_of_b_on_a.verify_packet_receipt_absence(
+                conn_end_on_a.counterparty().prefix(),
+                &msg.proof_unreceived_on_b,
+                consensus_state_of_b_on_a.root(),
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            ),
             Order::None => {
                 return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
                     expected: "Channel ordering cannot be None".to_string(),
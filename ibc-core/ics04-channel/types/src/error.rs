@@ -136,6 +136,8 @@ pub enum PacketError {
     ZeroPacketSequence,
     /// packet data bytes cannot be empty
     ZeroPacketData,
+    /// packet data of `{len}` bytes exceeds the maximum of `{max}` bytes
+    PacketDataTooLarge { len: usize, max: usize },
     /// invalid timeout height for the packet
     InvalidTimeoutHeight,
     /// Invalid packet timeout timestamp value error: `{0}`
@@ -9,6 +9,10 @@ use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawP
 use super::timeout::TimeoutHeight;
 use crate::error::PacketError;
 
+/// The default maximum length, in bytes, of the data of packets sent by the
+/// host, i.e. 1 MiB.
+pub const DEFAULT_MAX_PACKET_DATA_LEN: usize = 1024 * 1024;
+
 /// Enumeration of proof carrying ICS4 message, helper for relayer.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PacketMsgType {
@@ -138,12 +142,48 @@ impl Packet {
     /// instead of the common-case where it results in
     /// [`MsgRecvPacket`](crate::msgs::MsgRecvPacket).
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
-        let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
+        self.timeout_status(dst_chain_height, *dst_chain_ts)
+            .is_timed_out()
+    }
+
+    /// Classifies whether the packet is timed-out relative to the
+    /// destination chain's current height `dest_height` and timestamp
+    /// `dest_timestamp`, and by which of its timeout fields.
+    ///
+    /// Unset (zero) timeout fields never cause a time-out. A set timeout is
+    /// reached once the destination chain's value is strictly past it.
+    pub fn timeout_status(&self, dest_height: Height, dest_timestamp: Timestamp) -> TimeoutStatus {
+        let height_timed_out = self.timeout_height_on_b.has_expired(dest_height);
 
         let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
-            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+            && dest_timestamp.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+
+        match (height_timed_out, timestamp_timed_out) {
+            (false, false) => TimeoutStatus::NotTimedOut,
+            (true, false) => TimeoutStatus::TimedOutByHeight,
+            (false, true) => TimeoutStatus::TimedOutByTimestamp,
+            (true, true) => TimeoutStatus::TimedOutByHeightAndTimestamp,
+        }
+    }
+}
+
+/// The timeout status of a packet relative to the destination chain, as
+/// returned by [`Packet::timeout_status`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutStatus {
+    /// Neither the timeout height nor the timeout timestamp has been reached.
+    NotTimedOut,
+    /// Only the timeout height has been reached.
+    TimedOutByHeight,
+    /// Only the timeout timestamp has been reached.
+    TimedOutByTimestamp,
+    /// Both the timeout height and the timeout timestamp have been reached.
+    TimedOutByHeightAndTimestamp,
+}
 
-        height_timed_out || timestamp_timed_out
+impl TimeoutStatus {
+    pub fn is_timed_out(&self) -> bool {
+        !matches!(self, Self::NotTimedOut)
     }
 }
 
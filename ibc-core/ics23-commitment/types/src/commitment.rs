@@ -50,6 +50,22 @@ impl CommitmentRoot {
     pub fn is_empty(&self) -> bool {
         self.bytes.is_empty()
     }
+
+    /// Encodes the root as an upper-case hex string, as displayed for app
+    /// hashes by block explorers.
+    pub fn to_hex(&self) -> String {
+        Hex::upper_case()
+            .encode_to_string(&self.bytes)
+            .expect("hex encoding never fails")
+    }
+
+    /// Decodes a root from its hex representation.
+    pub fn from_hex(hex: &str) -> Result<Self, CommitmentError> {
+        Hex::upper_case()
+            .decode(hex.to_ascii_uppercase())
+            .map(Self::from)
+            .map_err(|e| CommitmentError::InvalidHexRoot(e.to_string()))
+    }
 }
 
 impl From<Vec<u8>> for CommitmentRoot {
@@ -193,3 +209,26 @@ impl serde::Serialize for CommitmentPrefix {
         format!("{self:?}").serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_root_hex_roundtrip() {
+        let root = CommitmentRoot::from_bytes(&[0xAB; 32]);
+
+        let hex = root.to_hex();
+        assert_eq!(hex, "AB".repeat(32));
+        assert_eq!(CommitmentRoot::from_hex(&hex).expect("valid hex"), root);
+        assert_eq!(
+            CommitmentRoot::from_hex(&hex.to_lowercase()).expect("valid hex"),
+            root
+        );
+
+        assert!(matches!(
+            CommitmentRoot::from_hex("ABC"),
+            Err(CommitmentError::InvalidHexRoot(_))
+        ));
+    }
+}
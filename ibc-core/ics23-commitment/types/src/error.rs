@@ -7,7 +7,7 @@ use ibc_primitives::prelude::*;
 pub enum CommitmentError {
     /// empty commitment prefix
     EmptyCommitmentPrefix,
-    /// empty merkle proof
+    /// empty merkle proof: it contains no commitment proofs
     EmptyMerkleProof,
     /// empty merkle root
     EmptyMerkleRoot,
@@ -29,6 +29,8 @@ pub enum CommitmentError {
     EncodingFailure(String),
     /// decoding commitment proof bytes failed: `{0}`
     DecodingFailure(String),
+    /// commitment root is not a valid hex string: `{0}`
+    InvalidHexRoot(String),
     /// invalid prefix length range: `[{0}, {1}]`
     InvalidPrefixLengthRange(i32, i32),
     /// invalid child size: `{0}`
@@ -9,7 +9,7 @@ use ibc_proto::ics23::{
     HostFunctionsProvider, NonExistenceProof,
 };
 
-use crate::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
 
@@ -19,6 +19,17 @@ pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerkleP
     MerklePath { key_path }
 }
 
+/// Like [`apply_prefix`], but builds the key of a path by joining its
+/// `segments` with the given `separator`, for hosts which store IBC paths
+/// under keys with a non-default separator.
+pub fn apply_prefix_segments(
+    prefix: &CommitmentPrefix,
+    segments: &[String],
+    separator: &str,
+) -> MerklePath {
+    apply_prefix(prefix, vec![segments.join(separator)])
+}
+
 impl From<CommitmentRoot> for MerkleRoot {
     fn from(root: CommitmentRoot) -> Self {
         Self {
@@ -38,6 +49,12 @@ impl TryFrom<RawMerkleProof> for MerkleProof {
     type Error = CommitmentError;
 
     fn try_from(proof: RawMerkleProof) -> Result<Self, Self::Error> {
+        // Reject missing proofs early rather than failing later with an
+        // opaque verification error.
+        if proof.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+
         Ok(Self {
             proofs: proof.proofs,
         })
@@ -53,6 +70,20 @@ impl From<MerkleProof> for RawMerkleProof {
 }
 
 impl MerkleProof {
+    /// Encodes this proof into the [`CommitmentProofBytes`] carried by IBC
+    /// messages, e.g. to feed a programmatically constructed proof to
+    /// `verify_membership`.
+    ///
+    /// This is equivalent to the `TryFrom<MerkleProof>` conversion.
+    pub fn try_into_commitment_proof_bytes(self) -> Result<CommitmentProofBytes, CommitmentError> {
+        CommitmentProofBytes::try_from(self)
+    }
+
+    /// Verifies that `value` is stored under `keys` in the tree committed to
+    /// by `root`.
+    ///
+    /// The `specs` are matched positionally against the proofs, so there must
+    /// be exactly one spec per proof, in the same order.
     pub fn verify_membership<H: HostFunctionsProvider>(
         &self,
         specs: &ProofSpecs,
@@ -69,10 +100,10 @@ impl MerkleProof {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
-        if ics23_specs.len() != num {
+        if specs.len() != num {
             return Err(CommitmentError::NumberOfSpecsMismatch);
         }
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
         if keys.key_path.len() != num {
             return Err(CommitmentError::NumberOfKeysMismatch);
         }
@@ -115,6 +146,11 @@ impl MerkleProof {
         Ok(())
     }
 
+    /// Verifies that no value is stored under `keys` in the tree committed to
+    /// by `root`.
+    ///
+    /// As for [`MerkleProof::verify_membership`], there must be exactly one
+    /// spec per proof, in the same order.
     pub fn verify_non_membership<H: HostFunctionsProvider>(
         &self,
         specs: &ProofSpecs,
@@ -129,10 +165,10 @@ impl MerkleProof {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
-        if ics23_specs.len() != num {
+        if specs.len() != num {
             return Err(CommitmentError::NumberOfSpecsMismatch);
         }
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
         if keys.key_path.len() != num {
             return Err(CommitmentError::NumberOfKeysMismatch);
         }
@@ -178,3 +214,76 @@ fn calculate_non_existence_root<H: HostFunctionsProvider>(
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::ExistenceProof;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_prefix_segments() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("no error");
+        let segments = ["clients", "07-tendermint-0", "clientState"].map(String::from);
+
+        assert_eq!(
+            apply_prefix_segments(&prefix, &segments, "/"),
+            apply_prefix(
+                &prefix,
+                vec!["clients/07-tendermint-0/clientState".to_string()]
+            )
+        );
+        assert_eq!(
+            apply_prefix_segments(&prefix, &segments, ":").key_path,
+            vec![
+                "ibc".to_string(),
+                "clients:07-tendermint-0:clientState".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merkle_proof_rejects_empty_proofs() {
+        let res = MerkleProof::try_from(RawMerkleProof { proofs: vec![] });
+        assert!(matches!(res, Err(CommitmentError::EmptyMerkleProof)));
+
+        let res = <MerkleProof as Protobuf<RawMerkleProof>>::decode_vec(&[]);
+        assert!(res.is_err());
+
+        let res = MerkleProof::try_from(RawMerkleProof {
+            proofs: vec![CommitmentProof::default()],
+        });
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_merkle_proof_commitment_proof_bytes_roundtrip() {
+        let existence_proof = ExistenceProof {
+            key: b"key".to_vec(),
+            value: b"value".to_vec(),
+            leaf: None,
+            path: vec![],
+        };
+        let raw_proof = RawMerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(existence_proof.clone())),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(existence_proof)),
+                },
+            ],
+        };
+
+        let proof_bytes = CommitmentProofBytes::try_from(raw_proof).expect("non-empty proof");
+        let merkle_proof = MerkleProof::try_from(&proof_bytes).expect("valid proof");
+
+        assert_eq!(merkle_proof.proofs.len(), 2);
+        assert_eq!(
+            merkle_proof
+                .try_into_commitment_proof_bytes()
+                .expect("non-empty proof"),
+            proof_bytes
+        );
+    }
+}
@@ -9,13 +9,29 @@ use crate::error::CommitmentError;
 ///
 /// This type encapsulates different types of proof specifications, mostly predefined, e.g., for
 /// Cosmos-SDK.
+///
+/// The order of the specifications is significant: during verification, the
+/// spec at index `i` is matched against the `i`-th proof of a
+/// [`MerkleProof`](crate::merkle::MerkleProof), from the innermost (leaf-most)
+/// store outwards. All conversions, to and from the raw protobuf type as well
+/// as through `serde` (which encodes the specs as a sequence), preserve the
+/// insertion order.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProofSpecs(Vec<ProofSpec>);
 
 impl ProofSpecs {
     /// Returns the specification for Cosmos-SDK proofs
+    ///
+    /// This is equivalent to [`ProofSpecs::cosmos_sdk`].
     pub fn cosmos() -> Self {
+        Self::cosmos_sdk()
+    }
+
+    /// Returns the canonical pair of proof specifications used by Cosmos-SDK
+    /// chains: the IAVL spec for the application store, followed by the
+    /// Tendermint (simple merkle) spec for the multistore.
+    pub fn cosmos_sdk() -> Self {
         vec![
             ics23::iavl_spec(),       // Format of proofs-iavl (iavl merkle proofs)
             ics23::tendermint_spec(), // Format of proofs-tendermint (crypto/ merkle SimpleProof)
@@ -24,10 +40,43 @@ impl ProofSpecs {
         .expect("should convert successfully")
     }
 
+    /// Returns `true` if these proof specifications are the canonical
+    /// Cosmos-SDK ones, as returned by [`ProofSpecs::cosmos_sdk`].
+    ///
+    /// Clients tracking a Cosmos-SDK chain with any other proof specifications
+    /// will fail to verify all membership proofs.
+    pub fn is_cosmos_sdk(&self) -> bool {
+        self == &Self::cosmos_sdk()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Returns the number of proof specifications.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the proof specifications, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &RawProofSpec> {
+        self.0.iter().map(|spec| &spec.0)
+    }
+
+    /// Returns the proof specification at index `idx`, if any.
+    pub fn get(&self, idx: usize) -> Option<&RawProofSpec> {
+        self.0.get(idx).map(|spec| &spec.0)
+    }
+
+    /// Returns these proof specifications in reverse order.
+    ///
+    /// Since the specs are matched positionally against the proofs of a
+    /// [`MerkleProof`](crate::merkle::MerkleProof), a reversed sequence of
+    /// distinct specs fails to verify proofs the original sequence accepts.
+    pub fn reversed(&self) -> Self {
+        Self(self.0.iter().rev().cloned().collect())
+    }
+
     pub fn validate(&self) -> Result<(), CommitmentError> {
         if self.is_empty() {
             return Err(CommitmentError::EmptyProofSpecs);
@@ -196,6 +245,50 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_cosmos_sdk_proof_specs() {
+        let specs = ProofSpecs::cosmos_sdk();
+
+        assert!(specs.is_cosmos_sdk());
+        assert!(ProofSpecs::cosmos().is_cosmos_sdk());
+        assert_eq!(specs, ProofSpecs::cosmos());
+
+        let iavl_only = ProofSpecs::try_from(vec![ics23::iavl_spec()]).expect("no error");
+        assert!(!iavl_only.is_cosmos_sdk());
+
+        let reversed = ProofSpecs::try_from(vec![ics23::tendermint_spec(), ics23::iavl_spec()])
+            .expect("no error");
+        assert!(!reversed.is_cosmos_sdk());
+    }
+
+    #[test]
+    fn test_proof_specs_accessors() {
+        let specs = ProofSpecs::cosmos_sdk();
+
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs.iter().count(), specs.len());
+        assert_eq!(specs.get(0), Some(&ics23::iavl_spec()));
+        assert_eq!(specs.get(1), Some(&ics23::tendermint_spec()));
+        assert_eq!(specs.get(2), None);
+        assert!(specs
+            .iter()
+            .eq([ics23::iavl_spec(), ics23::tendermint_spec()].iter()));
+    }
+
+    #[test]
+    fn test_proof_specs_preserve_order() {
+        let specs = ProofSpecs::cosmos_sdk();
+        let reversed = specs.reversed();
+
+        assert_eq!(reversed.len(), specs.len());
+        assert_ne!(reversed, specs);
+        assert_eq!(reversed.reversed(), specs);
+
+        let raw: Vec<RawProofSpec> = reversed.clone().into();
+        assert_eq!(raw, vec![ics23::tendermint_spec(), ics23::iavl_spec()]);
+        assert_eq!(ProofSpecs::try_from(raw).expect("no error"), reversed);
+    }
+
     #[rstest]
     #[case(0, 0)]
     #[case(2, 2)]
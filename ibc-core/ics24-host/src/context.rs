@@ -2,11 +2,11 @@ use core::time::Duration;
 
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
-use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::packet::{Receipt, DEFAULT_MAX_PACKET_DATA_LEN};
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
-use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
+use ibc_core_connection_types::version::Version as ConnectionVersion;
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
@@ -85,7 +85,7 @@ pub trait ValidationContext {
         &self,
         counterparty_candidate_versions: &[ConnectionVersion],
     ) -> Result<ConnectionVersion, ContextError> {
-        let version = pick_version(
+        let version = ConnectionEnd::pick_version(
             &self.get_compatible_versions(),
             counterparty_candidate_versions,
         )?;
@@ -138,6 +138,21 @@ pub trait ValidationContext {
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns whether packets with neither a timeout height nor a timeout
+    /// timestamp may be sent. Such packets can never time out, so they are
+    /// rejected unless the host explicitly opts in.
+    fn allows_packets_without_timeout(&self) -> bool {
+        false
+    }
+
+    /// Returns the maximum length, in bytes, of the data of packets sent by
+    /// the host, which bounds the size of packet commitments and proofs.
+    ///
+    /// The default implementation returns [`DEFAULT_MAX_PACKET_DATA_LEN`].
+    fn max_packet_data_len(&self) -> usize {
+        DEFAULT_MAX_PACKET_DATA_LEN
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -73,10 +73,38 @@ impl ChainId {
     }
 
     /// Extract the revision number from the chain identifier
+    ///
+    /// The revision number is parsed once, upon creation. Chain identifiers
+    /// that are not in the {chain name}-{revision number} format have a
+    /// revision number of 0.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// assert_eq!(ChainId::new("ibc-5").unwrap().revision_number(), 5);
+    /// assert_eq!(ChainId::new("myhost").unwrap().revision_number(), 0);
+    /// ```
     pub fn revision_number(&self) -> u64 {
         self.revision_number
     }
 
+    /// Returns `true` if the chain identifier is in the
+    /// {chain name}-{revision number} format.
+    ///
+    /// This distinguishes an explicit revision number of 0 (e.g. `chainA-0`)
+    /// from the default revision number of chain identifiers without a
+    /// revision suffix (e.g. `chainA`).
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// assert!(ChainId::new("chainA-0").unwrap().has_revision());
+    /// assert!(!ChainId::new("chainA").unwrap().has_revision());
+    /// ```
+    pub fn has_revision(&self) -> bool {
+        self.split_chain_id().is_ok()
+    }
+
     /// Increases `ChainId`s revision number by one.
     /// Fails if the chain identifier is not in
     /// `{chain_name}-{revision_number}` format or
@@ -389,6 +417,21 @@ mod tests {
         assert!(ChainId::new(chain_id_str).is_err());
     }
 
+    #[rstest]
+    #[case("ibc-5", 5, true)]
+    #[case("chainA-0", 0, true)]
+    #[case("myhost", 0, false)]
+    #[case("chainA-01", 0, false)]
+    fn test_chain_id_revision(
+        #[case] raw_chain_id: &str,
+        #[case] revision_number: u64,
+        #[case] has_revision: bool,
+    ) {
+        let chain_id = ChainId::new(raw_chain_id).unwrap();
+        assert_eq!(chain_id.revision_number(), revision_number);
+        assert_eq!(chain_id.has_revision(), has_revision);
+    }
+
     #[test]
     fn test_inc_revision_number() {
         let mut chain_id = ChainId::new("chainA-1").unwrap();
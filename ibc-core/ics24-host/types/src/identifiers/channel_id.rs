@@ -48,6 +48,26 @@ impl ChannelId {
         CHANNEL_ID_PREFIX
     }
 
+    /// Returns the counter of this identifier, or `None` if it is not of the
+    /// standard form built by [`ChannelId::new`], as may be the case for
+    /// identifiers deserialized without validation.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ChannelId;
+    /// assert_eq!(ChannelId::new(12).counter(), Some(12));
+    /// ```
+    pub fn counter(&self) -> Option<u64> {
+        let counter = self
+            .0
+            .strip_prefix(Self::prefix())?
+            .strip_prefix('-')?
+            .parse()
+            .ok()?;
+
+        // Rules out e.g. leading zeros, which `new` never produces.
+        (Self::new(counter) == *self).then_some(counter)
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -97,3 +117,23 @@ impl PartialEq<str> for ChannelId {
         self.as_str().eq(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero("channel-0", Some(0))]
+    #[case::standard("channel-12", Some(12))]
+    #[case::max("channel-18446744073709551615", Some(u64::MAX))]
+    #[case::custom("my-channel", None)]
+    #[case::leading_zero("channel-012", None)]
+    #[case::overflow("channel-18446744073709551616", None)]
+    #[case::missing_dash("channel12", None)]
+    fn channel_id_counter(#[case] id: &str, #[case] counter: Option<u64>) {
+        // Bypasses validation, so as to cover non-standard identifiers too.
+        assert_eq!(ChannelId(id.to_string()).counter(), counter);
+    }
+}
@@ -3,6 +3,7 @@ use core::str::FromStr;
 use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::ClientType;
 use crate::error::IdentifierError;
 use crate::validate::{validate_client_identifier, validate_client_type};
 
@@ -57,6 +58,22 @@ impl ClientId {
         Self(client_id)
     }
 
+    /// Returns the client type embedded in this identifier, or `None` if it
+    /// is not of the `{client_type}-{counter}` form built by
+    /// [`ClientId::new`].
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ClientId;
+    /// let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    /// assert_eq!(client_id.client_type().unwrap().as_str(), "07-tendermint");
+    /// ```
+    pub fn client_type(&self) -> Option<ClientType> {
+        let (client_type, counter) = self.0.rsplit_once('-')?;
+        counter.parse::<u64>().ok()?;
+
+        ClientType::from_str(client_type).ok()
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -89,3 +106,24 @@ impl PartialEq<str> for ClientId {
         self.as_str().eq(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::tendermint("07-tendermint-0", Some("07-tendermint"))]
+    #[case::wasm("08-wasm-12", Some("08-wasm"))]
+    #[case::no_counter("07-tendermint", None)]
+    #[case::non_numeric_counter("07-tendermint-a", None)]
+    #[case::too_short_client_type("07-tm-123", None)]
+    fn client_id_client_type(#[case] id: &str, #[case] client_type: Option<&str>) {
+        let client_id = ClientId::from_str(id).unwrap();
+        assert_eq!(
+            client_id.client_type().as_ref().map(ClientType::as_str),
+            client_type
+        );
+    }
+}
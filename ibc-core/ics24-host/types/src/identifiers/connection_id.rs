@@ -47,6 +47,26 @@ impl ConnectionId {
         CONNECTION_ID_PREFIX
     }
 
+    /// Returns the counter of this identifier, or `None` if it is not of the
+    /// standard form built by [`ConnectionId::new`], as may be the case for
+    /// identifiers deserialized without validation.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ConnectionId;
+    /// assert_eq!(ConnectionId::new(12).counter(), Some(12));
+    /// ```
+    pub fn counter(&self) -> Option<u64> {
+        let counter = self
+            .0
+            .strip_prefix(Self::prefix())?
+            .strip_prefix('-')?
+            .parse()
+            .ok()?;
+
+        // Rules out e.g. leading zeros, which `new` never produces.
+        (Self::new(counter) == *self).then_some(counter)
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -91,3 +111,23 @@ impl PartialEq<str> for ConnectionId {
         self.as_str().eq(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero("connection-0", Some(0))]
+    #[case::standard("connection-12", Some(12))]
+    #[case::max("connection-18446744073709551615", Some(u64::MAX))]
+    #[case::custom("my-connection", None)]
+    #[case::leading_zero("connection-012", None)]
+    #[case::overflow("connection-18446744073709551616", None)]
+    #[case::missing_dash("connection12", None)]
+    fn connection_id_counter(#[case] id: &str, #[case] counter: Option<u64>) {
+        // Bypasses validation, so as to cover non-standard identifiers too.
+        assert_eq!(ConnectionId(id.to_string()).counter(), counter);
+    }
+}
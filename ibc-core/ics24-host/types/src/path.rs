@@ -665,6 +665,21 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+impl UpgradeClientPath {
+    /// Returns the paths under which the upgraded client state and consensus
+    /// state are committed for an upgrade at the given revision `height`, in
+    /// that order.
+    ///
+    /// Their string representations are the keys of the Cosmos SDK upgrade
+    /// module, e.g. `upgradedIBCState/{height}/upgradedClient`.
+    pub fn for_height(height: u64) -> (Self, Self) {
+        (
+            Self::UpgradedClientState(height),
+            Self::UpgradedClientConsensusState(height),
+        )
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -696,6 +711,19 @@ impl Path {
     pub fn into_bytes(self) -> Vec<u8> {
         self.to_string().into_bytes()
     }
+
+    /// Returns the individual key segments of the path, e.g.
+    /// `["clients", "07-tendermint-0", "clientState"]`, for hosts joining
+    /// them with a separator other than `/`.
+    ///
+    /// Joining the segments with `/` yields the string representation of the
+    /// path. No segment contains a `/`, as identifiers never do.
+    pub fn key_segments(&self) -> Vec<String> {
+        self.to_string()
+            .split('/')
+            .map(ToString::to_string)
+            .collect()
+    }
 }
 
 #[derive(Debug, displaydoc::Display)]
@@ -1183,6 +1211,29 @@ mod tests {
         assert_eq!(path_str, path.to_string());
     }
 
+    #[test]
+    fn test_key_segments() {
+        let client_id = ClientId::new_dummy();
+
+        assert_eq!(
+            Path::ClientState(ClientStatePath::new(client_id.clone())).key_segments(),
+            vec!["clients", "07-tendermint-0", "clientState"]
+        );
+        assert_eq!(
+            Path::ClientConsensusState(ClientConsensusStatePath::new(client_id, 15, 31))
+                .key_segments(),
+            vec!["clients", "07-tendermint-0", "consensusStates", "15-31"]
+        );
+        assert_eq!(
+            Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(0)).key_segments(),
+            vec!["upgradedIBCState", "0", "upgradedClient"]
+        );
+        assert_eq!(
+            Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0)).key_segments(),
+            vec!["upgradedIBCState", "0", "upgradedConsState"]
+        );
+    }
+
     #[rstest::rstest]
     #[case("clients/clientType")]
     #[case("channels/channel-0")]
@@ -1403,4 +1454,26 @@ mod tests {
             )),
         )
     }
+
+    #[test]
+    fn test_upgrade_client_paths_for_height() {
+        let (client_state_path, consensus_state_path) = UpgradeClientPath::for_height(42);
+
+        assert_eq!(
+            client_state_path,
+            UpgradeClientPath::UpgradedClientState(42)
+        );
+        assert_eq!(
+            consensus_state_path,
+            UpgradeClientPath::UpgradedClientConsensusState(42)
+        );
+        assert_eq!(
+            client_state_path.to_string(),
+            "upgradedIBCState/42/upgradedClient"
+        );
+        assert_eq!(
+            consensus_state_path.to_string(),
+            "upgradedIBCState/42/upgradedConsState"
+        );
+    }
 }
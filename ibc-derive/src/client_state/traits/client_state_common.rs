@@ -35,6 +35,12 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {validate_proof_height(cs, proof_height)},
         imports,
     );
+    let capabilities_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {capabilities(cs)},
+        imports,
+    );
     let verify_upgrade_client_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -61,6 +67,7 @@ pub(crate) fn impl_ClientStateCommon(
     let CommitmentPrefix = imports.commitment_prefix();
     let CommitmentProofBytes = imports.commitment_proof_bytes();
     let ClientStateCommon = imports.client_state_common();
+    let ClientCapabilities = imports.client_capabilities();
     let ClientType = imports.client_type();
     let ClientError = imports.client_error();
     let Height = imports.height();
@@ -91,6 +98,12 @@ pub(crate) fn impl_ClientStateCommon(
                 }
             }
 
+            fn capabilities(&self) -> #ClientCapabilities {
+                match self {
+                    #(#capabilities_impl),*
+                }
+            }
+
             fn verify_upgrade_client(
                 &self,
                 upgraded_client_state: #Any,
@@ -37,6 +37,14 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let check_update_allowed_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { check_update_allowed(cs, ctx, client_id) },
+        imports,
+    );
+
     let check_substitute_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -97,6 +105,16 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn check_update_allowed(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#check_update_allowed_impl),*
+                }
+            }
+
             fn check_substitute(
                 &self,
                 ctx: &#V,
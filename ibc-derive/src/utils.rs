@@ -60,6 +60,11 @@ impl Imports {
         quote! {#Prefix::client::context::client_state::ClientStateCommon}
     }
 
+    pub fn client_capabilities(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::client::context::client_state::ClientCapabilities}
+    }
+
     pub fn client_state_validation(&self) -> TokenStream {
         let Prefix = self.prefix();
         quote! {#Prefix::client::context::client_state::ClientStateValidation}
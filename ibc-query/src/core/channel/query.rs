@@ -2,8 +2,8 @@
 
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ClientStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+    SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::primitives::prelude::format;
@@ -178,11 +178,9 @@ where
             ))
         })??;
 
-    let consensus_path = ClientConsensusStatePath::new(
-        connection_end.client_id().clone(),
-        request.consensus_height.revision_number(),
-        request.consensus_height.revision_height(),
-    );
+    let consensus_path = request
+        .consensus_height
+        .consensus_state_path(connection_end.client_id());
     let client_val_ctx = ibc_ctx.get_client_validation_context();
 
     let consensus_state = client_val_ctx.consensus_state(&consensus_path)?;
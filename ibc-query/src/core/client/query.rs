@@ -3,9 +3,7 @@
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::host::types::path::{
-    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
-};
+use ibc::core::host::types::path::{ClientStatePath, Path, UpgradeClientPath};
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::cosmos_host::upgrade_proposal::{UpgradeValidationContext, UpgradedConsensusStateRef};
 use ibc::primitives::prelude::format;
@@ -95,11 +93,8 @@ where
     let (height, consensus_state) = if let Some(height) = request.consensus_height {
         let client_val_ctx = ibc_ctx.get_client_validation_context();
 
-        let consensus_state = client_val_ctx.consensus_state(&ClientConsensusStatePath::new(
-            client_id.clone(),
-            height.revision_number(),
-            height.revision_height(),
-        ))?;
+        let consensus_state =
+            client_val_ctx.consensus_state(&height.consensus_state_path(&client_id))?;
 
         (height, consensus_state)
     } else {
@@ -122,11 +117,7 @@ where
     let proof = ibc_ctx
         .get_proof(
             proof_height,
-            &Path::ClientConsensusState(ClientConsensusStatePath::new(
-                client_id.clone(),
-                height.revision_number(),
-                height.revision_height(),
-            )),
+            &Path::ClientConsensusState(height.consensus_state_path(&client_id)),
         )
         .ok_or_else(|| {
             QueryError::proof_not_found(format!(
@@ -179,7 +170,7 @@ where
     ))
 }
 
-/// Queries for the status (Active, Frozen, Expired, Unauthorized) of a given client.
+/// Queries for the status (Active, Frozen, Expired, Unauthorized, Unknown) of a given client.
 pub fn query_client_status<I>(
     ibc_ctx: &I,
     request: &QueryClientStatusRequest,
@@ -1,9 +1,7 @@
 //! Provides utility functions for querying IBC connection states.
 
 use ibc::core::client::context::ClientValidationContext;
-use ibc::core::host::types::path::{
-    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
-};
+use ibc::core::host::types::path::{ClientConnectionPath, ClientStatePath, ConnectionPath, Path};
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::primitives::prelude::format;
 use ibc::primitives::proto::Any;
@@ -154,11 +152,9 @@ where
 {
     let connection_end = ibc_ctx.connection_end(&request.connection_id)?;
 
-    let consensus_path = ClientConsensusStatePath::new(
-        connection_end.client_id().clone(),
-        request.height.revision_number(),
-        request.height.revision_height(),
-    );
+    let consensus_path = request
+        .height
+        .consensus_state_path(connection_end.client_id());
 
     let client_val_ctx = ibc_ctx.get_client_validation_context();
 
@@ -0,0 +1,52 @@
+//! Benchmarks the verification of Tendermint headers signed by validator sets
+//! of various sizes.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc::clients::tendermint::client_state::verify_header;
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_header_with_validators, HeaderVerificationSetup,
+};
+use tendermint::crypto::default::Sha256;
+use tendermint_light_client_verifier::ProdVerifier;
+
+fn bench_verify_header(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify_header");
+
+    for validator_set_size in [10, 100, 150] {
+        let HeaderVerificationSetup {
+            ctx,
+            client_id,
+            client_state,
+            header,
+        } = dummy_header_with_validators(validator_set_size);
+
+        let chain_id = client_state.inner().chain_id.clone();
+        let options = client_state
+            .inner()
+            .as_light_client_options()
+            .expect("valid options");
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(validator_set_size),
+            &header,
+            |b, header| {
+                b.iter(|| {
+                    verify_header::<_, Sha256>(
+                        &ctx.ibc_store,
+                        header,
+                        &client_id,
+                        &chain_id,
+                        &options,
+                        &ProdVerifier::default(),
+                    )
+                    .expect("header verifies")
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify_header);
+criterion_main!(benches);
@@ -15,8 +15,8 @@ use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    ChannelEndPath, ClientStatePath, CommitmentPath, ConnectionPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::primitives::prelude::*;
@@ -289,11 +289,7 @@ where
         height: Height,
         consensus_state: AnyConsensusState,
     ) -> Self {
-        let consensus_state_path = ClientConsensusStatePath::new(
-            client_id.clone(),
-            height.revision_number(),
-            height.revision_height(),
-        );
+        let consensus_state_path = height.consensus_state_path(client_id);
         self.ibc_store
             .store_consensus_state(consensus_state_path, consensus_state)
             .expect("error writing to store");
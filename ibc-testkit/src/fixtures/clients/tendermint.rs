@@ -5,19 +5,25 @@ use basecoin_store::avl::get_proof_spec as basecoin_proof_spec;
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::error::{Error as ClientError, Error};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
-#[cfg(feature = "serde")]
-use ibc::clients::tendermint::types::Header;
 use ibc::clients::tendermint::types::{
-    AllowUpdate, ClientState as ClientStateType, TrustThreshold,
+    client_type as tm_client_type, AllowUpdate, ClientState as ClientStateType, Header,
+    TrustThreshold,
 };
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
-use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
 use ibc::core::primitives::prelude::*;
 use tendermint::block::Header as TmHeader;
+use tendermint_testgen::Validator as TestgenValidator;
 use typed_builder::TypedBuilder;
 
+use crate::context::{MockContext, TendermintContext};
+use crate::fixtures::core::context::TestContextConfig;
+use crate::hosts::tendermint::BlockParams;
+use crate::hosts::{MockHost, TendermintHost, TestBlock};
+use crate::testapp::ibc::core::types::LightClientBuilder;
+
 /// Returns a dummy tendermint `ClientState` by given `frozen_height`, for testing purposes only!
 pub fn dummy_tm_client_state_from_raw(frozen_height: RawHeight) -> Result<TmClientState, Error> {
     ClientStateType::try_from(dummy_raw_tm_client_state(frozen_height)).map(TmClientState::from)
@@ -33,7 +39,7 @@ pub fn dummy_tm_client_state_from_header(tm_header: TmHeader) -> TmClientState {
         Duration::from_secs(128_000),
         Duration::from_millis(3000),
         Height::new(chain_id.revision_number(), u64::from(tm_header.height)).expect("Never fails"),
-        ProofSpecs::cosmos(),
+        ProofSpecs::cosmos_sdk(),
         Vec::new(),
         AllowUpdate {
             after_expiry: false,
@@ -58,7 +64,7 @@ pub fn dummy_raw_tm_client_state(frozen_height: RawHeight) -> RawTmClientState {
         unbonding_period: Some(Duration::from_secs(128_000).into()),
         max_clock_drift: Some(Duration::from_millis(3000).into()),
         latest_height: Some(Height::new(0, 10).expect("Never fails").into()),
-        proof_specs: ProofSpecs::cosmos().into(),
+        proof_specs: ProofSpecs::cosmos_sdk().into(),
         upgrade_path: Vec::new(),
         frozen_height: Some(frozen_height),
         allow_update_after_expiry: false,
@@ -111,6 +117,74 @@ impl ClientStateConfig {
     }
 }
 
+/// Returns `n` validators of equal voting power, e.g. to produce blocks
+/// signed by a validator set of realistic size.
+pub fn dummy_validators(n: usize) -> Vec<TestgenValidator> {
+    (0..n)
+        .map(|i| TestgenValidator::new(&format!("validator-{i}")).voting_power(10))
+        .collect()
+}
+
+/// Everything needed to call `verify_header` on the Tendermint client
+/// identified by `client_id`, hosted by `ctx`, with `header`.
+pub struct HeaderVerificationSetup {
+    pub ctx: MockContext,
+    pub client_id: ClientId,
+    pub client_state: TmClientState,
+    pub header: Header,
+}
+
+/// Returns a [`HeaderVerificationSetup`] whose header is signed by `n`
+/// validators and is adjacent to the trusted consensus state of the client,
+/// e.g. to benchmark header verification for various validator-set sizes.
+pub fn dummy_header_with_validators(n: usize) -> HeaderVerificationSetup {
+    let client_id = tm_client_type().build_client_id(0);
+    let trusted_height = Height::new(1, 2).expect("Never fails");
+    let header_height = trusted_height.increment();
+
+    let block_params = BlockParams::from_validator_history(vec![dummy_validators(n); 3]);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").expect("Never fails"))
+                .build(),
+        )
+        .latest_height(header_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([trusted_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").expect("Never fails"))
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).expect("Never fails"))
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let trusted_block = ctx_b.host_block(&trusted_height).expect("Never fails");
+    let header = ctx_b
+        .host_block(&header_height)
+        .expect("Never fails")
+        .into_header_with_trusted(&trusted_block)
+        .into();
+
+    HeaderVerificationSetup {
+        ctx,
+        client_id,
+        client_state,
+        header,
+    }
+}
+
 #[cfg(feature = "serde")]
 pub fn dummy_tendermint_header() -> tendermint::block::Header {
     use tendermint::block::signed_header::SignedHeader;
@@ -175,10 +249,22 @@ pub fn dummy_ics07_header() -> Header {
 #[cfg(all(test, feature = "serde"))]
 mod tests {
 
-    use ibc::primitives::proto::Any;
+    use ibc::clients::tendermint::types::{
+        ConsensusState as TmConsensusState, Misbehaviour as TmMisbehaviour,
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+    };
+    use ibc::core::client::context::client_state::{ClientCapabilities, ClientStateCommon};
+    use ibc::core::host::types::identifiers::ClientId;
+    use ibc::core::primitives::Timestamp;
+    use ibc::primitives::proto::{Any, Duration as ProtoDuration};
     use rstest::rstest;
+    use tendermint::crypto::default::Sha256;
+    use tendermint::validator::Set as ValidatorSet;
+    use tendermint::Hash;
+    use tendermint_testgen::Generator;
 
     use super::*;
+    use crate::testapp::ibc::clients::AnyClientState;
 
     #[rstest]
     // try conversions for when the client is not frozen
@@ -220,6 +306,332 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::missing_trust_level(|raw| raw.trust_level = None, Error::MissingTrustLevel)]
+    #[case::malformed_trust_level(
+        |raw| raw.trust_level = Some(Fraction { numerator: 1, denominator: 0 }),
+        Error::InvalidTrustThreshold { reason: String::new() }
+    )]
+    #[case::missing_trusting_period(|raw| raw.trusting_period = None, Error::MissingTrustingPeriod)]
+    #[case::malformed_trusting_period(
+        |raw| raw.trusting_period = Some(negative_duration()),
+        Error::InvalidTrustingPeriod
+    )]
+    #[case::missing_unbonding_period(
+        |raw| raw.unbonding_period = None,
+        Error::MissingUnbondingPeriod
+    )]
+    #[case::malformed_unbonding_period(
+        |raw| raw.unbonding_period = Some(negative_duration()),
+        Error::InvalidUnbondingPeriod
+    )]
+    #[case::missing_max_clock_drift(|raw| raw.max_clock_drift = None, Error::MissingMaxClockDrift)]
+    #[case::malformed_max_clock_drift(
+        |raw| raw.max_clock_drift = Some(negative_duration()),
+        Error::NegativeMaxClockDrift
+    )]
+    #[case::missing_latest_height(|raw| raw.latest_height = None, Error::MissingLatestHeight)]
+    #[case::malformed_latest_height(
+        |raw| raw.latest_height = Some(RawHeight { revision_number: 0, revision_height: 0 }),
+        Error::InvalidLatestHeight { reason: String::new() }
+    )]
+    fn tm_client_state_from_raw_field_errors(
+        #[case] corrupt: fn(&mut RawTmClientState),
+        #[case] expected: Error,
+    ) {
+        let mut raw = dummy_raw_tm_client_state(RawHeight {
+            revision_number: 0,
+            revision_height: 0,
+        });
+        corrupt(&mut raw);
+
+        let err = ClientStateType::try_from(raw).expect_err("raw client state is invalid");
+        assert_eq!(
+            core::mem::discriminant(&err),
+            core::mem::discriminant(&expected),
+            "{err}"
+        );
+    }
+
+    fn negative_duration() -> ProtoDuration {
+        ProtoDuration {
+            seconds: -1,
+            nanos: 0,
+        }
+    }
+
+    #[test]
+    fn tm_header_height_and_timestamp() {
+        let header = dummy_ics07_header();
+
+        let (height, timestamp): (Height, Timestamp) = (&header).into();
+
+        assert_eq!(height, header.height());
+        assert_eq!(timestamp, header.timestamp());
+        assert_eq!(
+            timestamp.into_tm_time(),
+            Some(header.signed_header.header.time)
+        );
+    }
+
+    #[test]
+    fn tm_header_to_height() {
+        let header = dummy_ics07_header();
+        let chain_id =
+            ChainId::from_str(header.signed_header.header.chain_id.as_str()).expect("Never fails");
+
+        let expected = Height::new(
+            chain_id.revision_number(),
+            u64::from(header.signed_header.header.height),
+        )
+        .expect("Never fails");
+
+        assert_eq!(header.to_height(&chain_id).expect("Never fails"), expected);
+        assert_eq!(header.height(), expected);
+
+        let other_chain_id = ChainId::new("otherchain-7").expect("Never fails");
+        assert!(header.to_height(&other_chain_id).is_err());
+    }
+
+    #[test]
+    fn tm_header_decode_with_limit() {
+        let header = dummy_ics07_header();
+        let any = Any::from(header.clone());
+        let len = any.value.len();
+
+        assert_eq!(
+            Header::decode_with_limit(&any, len).expect("Never fails"),
+            header
+        );
+        assert!(Header::decode_with_limit(&any, len - 1).is_err());
+    }
+
+    #[test]
+    fn tm_client_state_capabilities() {
+        let client_state = dummy_tm_client_state_from_header(dummy_tendermint_header());
+
+        assert_eq!(client_state.capabilities(), ClientCapabilities::ALL);
+        assert_eq!(
+            AnyClientState::from(client_state).capabilities(),
+            ClientCapabilities::ALL
+        );
+    }
+
+    #[test]
+    fn tm_header_validator_sets() {
+        let header = dummy_ics07_header();
+
+        assert_eq!(header.validator_set(), &header.validator_set);
+        assert_eq!(header.validator_set().validators().len(), 1);
+        assert_eq!(
+            header.trusted_next_validator_set(),
+            &header.trusted_next_validator_set
+        );
+    }
+
+    #[test]
+    fn tm_client_state_from_trusted_header() {
+        let header = dummy_ics07_header();
+        let chain_params = ClientStateConfig::default()
+            .into_client_state(
+                ChainId::new("test-chain-1").expect("no error"),
+                Height::new(1, 1).expect("no error"),
+            )
+            .expect("no error")
+            .inner()
+            .clone();
+
+        let (client_state, consensus_state) =
+            ClientStateType::from_trusted_header(chain_params.clone(), &header).expect("no error");
+
+        assert_eq!(client_state.latest_height, header.height());
+        assert_eq!(client_state.chain_id, chain_params.chain_id);
+        assert_eq!(client_state.trusting_period, chain_params.trusting_period);
+        assert!(!client_state.is_frozen());
+        client_state.validate().expect("no error");
+
+        assert_eq!(consensus_state, TmConsensusState::from(header.clone()));
+        assert_eq!(consensus_state.timestamp(), header.timestamp());
+
+        let other_chain_params = ClientStateType {
+            chain_id: ChainId::new("other-chain-1").expect("no error"),
+            ..chain_params
+        };
+        assert!(matches!(
+            ClientStateType::from_trusted_header(other_chain_params, &header),
+            Err(Error::MismatchHeaderChainId { .. })
+        ));
+    }
+
+    #[test]
+    fn tm_client_state_estimate_verify_cost() {
+        let client_state = ClientStateConfig::default()
+            .into_client_state(
+                ChainId::new("test-chain-1").expect("no error"),
+                Height::new(1, 1).expect("no error"),
+            )
+            .expect("no error")
+            .inner()
+            .clone();
+
+        let mut adjacent_header = dummy_ics07_header();
+        adjacent_header.trusted_height = adjacent_header
+            .height()
+            .decrement()
+            .expect("header is above height 1");
+
+        let mut non_adjacent_header = adjacent_header.clone();
+        non_adjacent_header.trusted_height = non_adjacent_header
+            .trusted_height
+            .sub(1)
+            .expect("header is above height 2");
+
+        let adjacent_cost = client_state.estimate_verify_cost(&adjacent_header);
+        assert!(adjacent_cost.is_adjacent);
+        assert_eq!(adjacent_cost.validator_set_size, 1);
+
+        let non_adjacent_cost = client_state.estimate_verify_cost(&non_adjacent_header);
+        assert!(!non_adjacent_cost.is_adjacent);
+        assert!(non_adjacent_cost.cost_units > adjacent_cost.cost_units);
+
+        let larger_validator_set = ValidatorSet::without_proposer(
+            ["a", "b", "c"]
+                .map(|id| {
+                    TestgenValidator::new(id)
+                        .voting_power(10)
+                        .generate()
+                        .unwrap()
+                })
+                .to_vec(),
+        );
+        let larger_header = Header {
+            validator_set: larger_validator_set,
+            ..adjacent_header
+        };
+
+        let larger_cost = client_state.estimate_verify_cost(&larger_header);
+        assert!(larger_cost.is_adjacent);
+        assert_eq!(larger_cost.validator_set_size, 3);
+        assert!(larger_cost.cost_units > adjacent_cost.cost_units);
+    }
+
+    #[test]
+    fn tm_consensus_state_content_eq() {
+        let consensus_state = TmConsensusState::from(dummy_ics07_header());
+
+        assert!(consensus_state.content_eq(&consensus_state));
+        assert!(consensus_state.content_eq(&TmConsensusState::new(
+            consensus_state.root(),
+            consensus_state.timestamp,
+            consensus_state.next_validators_hash(),
+        )));
+
+        let other_next_validators = TmConsensusState {
+            next_validators_hash: Hash::Sha256([0xAB; 32]),
+            ..consensus_state.clone()
+        };
+        assert!(!consensus_state.content_eq(&other_next_validators));
+    }
+
+    #[test]
+    fn tm_consensus_state_timestamp() {
+        let header = dummy_ics07_header();
+        let consensus_state = TmConsensusState::from(header.clone());
+
+        assert_eq!(
+            consensus_state.timestamp(),
+            Timestamp::from(header.signed_header.header.time)
+        );
+        assert_eq!(consensus_state.timestamp(), header.timestamp());
+    }
+
+    #[test]
+    fn tm_consensus_state_matches_next_validators() {
+        let header = dummy_ics07_header();
+
+        let consensus_state = TmConsensusState::new(
+            vec![0].into(),
+            header.signed_header.header.time,
+            header.trusted_next_validator_set.hash_with::<Sha256>(),
+        );
+        assert!(consensus_state.matches_next_validators::<Sha256>(&header));
+
+        let consensus_state = TmConsensusState {
+            next_validators_hash: Hash::Sha256([0; 32]),
+            ..consensus_state
+        };
+        assert!(!consensus_state.matches_next_validators::<Sha256>(&header));
+    }
+
+    #[test]
+    fn tm_misbehaviour_from_conflicting_headers() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("Never fails");
+        let header1 = dummy_ics07_header();
+
+        let mut header2 = header1.clone();
+        header2.signed_header.commit.block_id.hash = Hash::Sha256([0; 32]);
+
+        let misbehaviour = TmMisbehaviour::new(client_id.clone(), header1.clone(), header2)
+            .expect("headers at the same height with different hashes conflict");
+        assert_eq!(misbehaviour.client_id(), &client_id);
+        assert_eq!(misbehaviour.header1(), &header1);
+
+        // `header2` is at a greater height than `header1` but has the same
+        // block time, which violates monotonic time
+        let mut header2 = header1.clone();
+        header2.signed_header.header.height = header1.signed_header.header.height.increment();
+
+        TmMisbehaviour::new(client_id, header2, header1)
+            .expect("a higher header without a later time conflicts");
+    }
+
+    #[test]
+    fn tm_misbehaviour_any_roundtrip() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("Never fails");
+        let header1 = dummy_ics07_header();
+
+        let mut header2 = header1.clone();
+        header2.signed_header.commit.block_id.hash = Hash::Sha256([0; 32]);
+
+        let misbehaviour = TmMisbehaviour::new(client_id, header1, header2).expect("no error");
+
+        let any = misbehaviour.clone().to_any();
+        assert_eq!(any.type_url, TENDERMINT_MISBEHAVIOUR_TYPE_URL);
+        assert_eq!(any, Any::from(misbehaviour.clone()));
+
+        assert_eq!(
+            TmMisbehaviour::try_from(any).expect("no error"),
+            misbehaviour
+        );
+    }
+
+    #[test]
+    fn tm_misbehaviour_rejects_non_conflicting_headers() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("Never fails");
+        let header1 = dummy_ics07_header();
+
+        assert!(matches!(
+            TmMisbehaviour::new(client_id.clone(), header1.clone(), header1.clone()),
+            Err(Error::MisbehaviourHeadersBlockHashesEqual)
+        ));
+
+        let mut header2 = header1.clone();
+        header2.signed_header.header.height = header1.signed_header.header.height.increment();
+        header2.signed_header.header.time =
+            (header1.signed_header.header.time + Duration::from_secs(1)).expect("Never fails");
+
+        assert!(matches!(
+            TmMisbehaviour::new(client_id.clone(), header2, header1.clone()),
+            Err(Error::InvalidRawMisbehaviour { reason }) if reason.contains("time is later")
+        ));
+
+        let mut header2 = header1.clone();
+        header2.signed_header.header.chain_id = "other-chain".parse().expect("Never fails");
+        header2.signed_header.commit.block_id.hash = Hash::Sha256([0; 32]);
+
+        assert!(TmMisbehaviour::new(client_id, header1, header2).is_err());
+    }
+
     #[test]
     fn tm_client_state_from_header_healthy() {
         // check client state creation path from a tendermint header
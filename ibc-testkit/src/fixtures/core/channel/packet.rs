@@ -69,8 +69,11 @@ pub fn dummy_proof() -> Vec<u8> {
 mod tests {
     use ibc::core::channel::types::channel::Order;
     use ibc::core::channel::types::events::SendPacket;
+    use ibc::core::channel::types::packet::TimeoutStatus;
+    use ibc::core::client::types::Height;
     use ibc::core::handler::types::events::IbcEvent;
     use ibc::core::host::types::identifiers::ConnectionId;
+    use rstest::rstest;
 
     use super::*;
 
@@ -245,6 +248,48 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::no_timeouts(TimeoutHeight::Never, Timestamp::none(), TimeoutStatus::NotTimedOut)]
+    #[case::zero_timestamp(TimeoutHeight::Never, at_nanos(0), TimeoutStatus::NotTimedOut)]
+    #[case::height_not_reached(at(10), Timestamp::none(), TimeoutStatus::NotTimedOut)]
+    #[case::height_reached_exactly(at(5), Timestamp::none(), TimeoutStatus::NotTimedOut)]
+    #[case::height_passed(at(4), Timestamp::none(), TimeoutStatus::TimedOutByHeight)]
+    #[case::timestamp_not_reached(TimeoutHeight::Never, at_nanos(20), TimeoutStatus::NotTimedOut)]
+    #[case::timestamp_passed(TimeoutHeight::Never, at_nanos(9), TimeoutStatus::TimedOutByTimestamp)]
+    #[case::only_height_passed(at(4), at_nanos(20), TimeoutStatus::TimedOutByHeight)]
+    #[case::only_timestamp_passed(at(10), at_nanos(9), TimeoutStatus::TimedOutByTimestamp)]
+    #[case::both_passed(at(4), at_nanos(9), TimeoutStatus::TimedOutByHeightAndTimestamp)]
+    fn packet_timeout_status(
+        #[case] timeout_height_on_b: TimeoutHeight,
+        #[case] timeout_timestamp_on_b: Timestamp,
+        #[case] expected: TimeoutStatus,
+    ) {
+        // The destination chain is at height 0-5 and timestamp 10ns.
+        let dest_height = Height::new(0, 5).unwrap();
+        let dest_timestamp = at_nanos(10);
+
+        let packet: Packet = PacketConfig::builder()
+            .timeout_height_on_b(timeout_height_on_b)
+            .timeout_timestamp_on_b(timeout_timestamp_on_b)
+            .build();
+
+        let status = packet.timeout_status(dest_height, dest_timestamp);
+
+        assert_eq!(status, expected);
+        assert_eq!(
+            packet.timed_out(&dest_timestamp, dest_height),
+            status.is_timed_out()
+        );
+    }
+
+    fn at(revision_height: u64) -> TimeoutHeight {
+        TimeoutHeight::At(Height::new(0, revision_height).unwrap())
+    }
+
+    fn at_nanos(nanoseconds: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(nanoseconds).unwrap()
+    }
+
     #[test]
     fn to_and_from() {
         let raw = dummy_raw_packet(15, 0);
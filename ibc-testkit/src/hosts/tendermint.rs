@@ -182,13 +182,7 @@ impl TestHeader for TendermintHeader {
     type ConsensusState = ConsensusState;
 
     fn height(&self) -> Height {
-        Height::new(
-            ChainId::from_str(self.0.signed_header.header.chain_id.as_str())
-                .expect("Never fails")
-                .revision_number(),
-            self.0.signed_header.header.height.value(),
-        )
-        .expect("Never fails")
+        self.0.height()
     }
 
     fn timestamp(&self) -> Timestamp {
@@ -25,8 +25,7 @@ use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath,
+    AckPath, ChannelEndPath, ClientStatePath, CommitmentPath, ConnectionPath, ReceiptPath,
 };
 use ibc::core::host::ValidationContext;
 use ibc::primitives::Signer;
@@ -243,12 +242,9 @@ where
             .ibc_store()
             .get_proof(
                 proofs_height_on_a,
-                &ClientConsensusStatePath::new(
-                    client_id_on_a.clone(),
-                    consensus_height_of_b_on_a.revision_number(),
-                    consensus_height_of_b_on_a.revision_height(),
-                )
-                .into(),
+                &consensus_height_of_b_on_a
+                    .consensus_state_path(&client_id_on_a)
+                    .into(),
             )
             .expect("consensus state exists")
             .try_into()
@@ -326,12 +322,9 @@ where
             .ibc_store()
             .get_proof(
                 proofs_height_on_b,
-                &ClientConsensusStatePath::new(
-                    client_id_on_b.clone(),
-                    consensus_height_of_a_on_b.revision_number(),
-                    consensus_height_of_a_on_b.revision_height(),
-                )
-                .into(),
+                &consensus_height_of_a_on_b
+                    .consensus_state_path(&client_id_on_b)
+                    .into(),
             )
             .expect("consensus state exists")
             .try_into()
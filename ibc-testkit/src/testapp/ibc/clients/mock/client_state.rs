@@ -9,7 +9,7 @@ use ibc::core::commitment_types::commitment::{
 };
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ClientType};
-use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc::core::host::types::path::{ClientStatePath, Path};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
@@ -280,15 +280,11 @@ where
         }
 
         let latest_consensus_state: MockConsensusState = {
-            match ctx.consensus_state(&ClientConsensusStatePath::new(
-                client_id.clone(),
-                self.latest_height().revision_number(),
-                self.latest_height().revision_height(),
-            )) {
+            match ctx.consensus_state(&self.latest_height().consensus_state_path(client_id)) {
                 Ok(cs) => cs.try_into().map_err(Into::into)?,
                 // if the client state does not have an associated consensus state for its latest height
-                // then it must be expired
-                Err(_) => return Ok(Status::Expired),
+                // (e.g. it was never stored or has been pruned) then its status cannot be determined
+                Err(_) => return Ok(Status::Unknown),
             }
         };
 
@@ -328,11 +324,7 @@ where
 
         ctx.store_client_state(ClientStatePath::new(client_id.clone()), (*self).into())?;
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                self.latest_height().revision_number(),
-                self.latest_height().revision_height(),
-            ),
+            self.latest_height().consensus_state_path(client_id),
             mock_consensus_state.into(),
         )?;
         ctx.store_update_meta(
@@ -358,11 +350,9 @@ where
         let new_consensus_state = MockConsensusState::new(header);
 
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                new_client_state.latest_height().revision_number(),
-                new_client_state.latest_height().revision_height(),
-            ),
+            new_client_state
+                .latest_height()
+                .consensus_state_path(client_id),
             new_consensus_state.into(),
         )?;
         ctx.store_client_state(
@@ -408,11 +398,7 @@ where
         let latest_height = new_client_state.latest_height();
 
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                latest_height.revision_number(),
-                latest_height.revision_height(),
-            ),
+            latest_height.consensus_state_path(client_id),
             new_consensus_state.into(),
         )?;
         ctx.store_client_state(
@@ -455,11 +441,9 @@ where
         let mock_consensus_state: MockConsensusState = substitute_consensus_state.try_into()?;
 
         ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                subject_client_id.clone(),
-                new_mock_client_state.latest_height().revision_number(),
-                new_mock_client_state.latest_height().revision_height(),
-            ),
+            new_mock_client_state
+                .latest_height()
+                .consensus_state_path(subject_client_id),
             mock_consensus_state.into(),
         )?;
 
@@ -2,8 +2,10 @@ use core::fmt::Debug;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
+use ibc::clients::tendermint::context::TmValidationContext;
 use ibc::core::client::context::{
-    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+    ClientExecutionContext, ClientValidationContext, ConsensusStateCachePolicy,
+    ExtClientValidationContext,
 };
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
@@ -15,6 +17,7 @@ use ibc::core::host::types::path::{
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::prelude::*;
+use tendermint::validator::Set as ValidatorSet;
 
 use super::types::MockIbcStore;
 use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
@@ -85,6 +88,29 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns the lowest height at which a consensus state of the given
+    /// client is stored, without collecting all of them.
+    fn oldest_consensus_state_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        let path = format!("clients/{client_id}/consensusStates").into();
+
+        let oldest_height = self
+            .store
+            .get_keys(&path)
+            .into_iter()
+            .filter_map(|path| match path.try_into() {
+                Ok(Path::ClientConsensusState(path)) => {
+                    Height::new(path.revision_number, path.revision_height).ok()
+                }
+                _ => None,
+            })
+            .min();
+
+        Ok(oldest_height)
+    }
+
     fn next_consensus_state(
         &self,
         client_id: &ClientId,
@@ -151,6 +177,25 @@ where
         Ok(consensus_state)
     }
 }
+impl<S> TmValidationContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn max_header_validators(&self) -> usize {
+        self.max_header_validators
+    }
+
+    fn trusted_next_validator_set(
+        &self,
+        client_id: &ClientId,
+        trusted_height: Height,
+    ) -> Option<ValidatorSet> {
+        self.trusted_next_validator_sets
+            .lock()
+            .get(&(client_id.clone(), trusted_height))
+            .cloned()
+    }
+}
 
 impl<S> ClientValidationContext for MockIbcStore<S>
 where
@@ -168,6 +213,16 @@ where
             })?)
     }
 
+    /// Checks for the raw client state key, skipping the decoding.
+    fn client_exists(&self, client_id: &ClientId) -> bool {
+        self.store
+            .get(
+                StoreHeight::Pending,
+                &ClientStatePath::new(client_id.clone()).to_string().into(),
+            )
+            .is_some()
+    }
+
     fn consensus_state(
         &self,
         client_cons_state_path: &ClientConsensusStatePath,
@@ -222,6 +277,43 @@ where
 
         Ok((processed_timestamp, processed_height))
     }
+
+    /// Returns the identifiers of all the clients stored in the mock store.
+    fn clients(&self) -> Result<Vec<ClientId>, ContextError> {
+        let path = "clients".to_owned().into();
+
+        Ok(self
+            .client_state_store
+            .get_keys(&path)
+            .into_iter()
+            .filter_map(|path| {
+                if let Ok(Path::ClientState(client_path)) = path.try_into() {
+                    Some(client_path.0)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    fn genesis_consensus_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(self
+            .genesis_consensus_heights
+            .lock()
+            .get(client_id)
+            .copied())
+    }
+
+    fn requires_adjacent_updates(&self, _client_id: &ClientId) -> bool {
+        self.require_adjacent_updates
+    }
+
+    fn is_client_paused(&self, client_id: &ClientId) -> Result<bool, ContextError> {
+        Ok(self.paused_clients.lock().contains(client_id))
+    }
 }
 
 impl<S> ClientExecutionContext for MockIbcStore<S>
@@ -322,4 +414,71 @@ where
             })?;
         Ok(())
     }
+
+    fn store_genesis_consensus_height(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.genesis_consensus_heights
+            .lock()
+            .insert(client_id.clone(), height);
+        Ok(())
+    }
+
+    fn pause_client(&mut self, client_id: &ClientId) -> Result<(), ContextError> {
+        self.paused_clients.lock().insert(client_id.clone());
+        Ok(())
+    }
+
+    fn unpause_client(&mut self, client_id: &ClientId) -> Result<(), ContextError> {
+        self.paused_clients.lock().remove(client_id);
+        Ok(())
+    }
+
+    fn on_client_updated(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        let mut client_metrics = self.client_metrics.lock();
+        let metrics = client_metrics.entry(client_id.clone()).or_default();
+        metrics.updates += 1;
+        metrics.last_updated_height = Some(height);
+        Ok(())
+    }
+
+    fn on_misbehaviour_detected(&mut self, client_id: &ClientId) -> Result<(), ContextError> {
+        self.client_metrics
+            .lock()
+            .entry(client_id.clone())
+            .or_default()
+            .misbehaviours += 1;
+        Ok(())
+    }
+
+    fn on_consensus_state_pruned(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.client_metrics
+            .lock()
+            .entry(client_id.clone())
+            .or_default()
+            .pruned_heights
+            .push(height);
+        Ok(())
+    }
+
+    /// Clients are only created through the create-client handler.
+    fn requires_typed_client_ids(&self) -> bool {
+        true
+    }
+
+    fn consensus_state_cache_policy(&self) -> Option<&dyn ConsensusStateCachePolicy> {
+        self.consensus_state_cache_policy
+            .as_ref()
+            .map(|policy| policy as &dyn ConsensusStateCachePolicy)
+    }
 }
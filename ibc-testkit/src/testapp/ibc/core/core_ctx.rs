@@ -263,6 +263,14 @@ where
         Ok(())
     }
 
+    fn allows_packets_without_timeout(&self) -> bool {
+        self.allow_packets_without_timeout
+    }
+
+    fn max_packet_data_len(&self) -> usize {
+        self.max_packet_data_len
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
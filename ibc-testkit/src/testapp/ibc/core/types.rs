@@ -1,18 +1,22 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
 use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedStore};
+use ibc::clients::tendermint::context::DEFAULT_MAX_HEADER_VALIDATORS;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::DEFAULT_MAX_PACKET_DATA_LEN;
 use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::{ConsensusStateCachePolicy, ConsensusStateMeta};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
@@ -27,6 +31,7 @@ use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
 use ibc_proto::ics23::CommitmentProof;
 use parking_lot::Mutex;
+use tendermint::validator::Set as ValidatorSet;
 use typed_builder::TypedBuilder;
 
 use crate::context::{MockStore, TestContext};
@@ -38,6 +43,70 @@ pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
 pub type DefaultIbcStore = MockIbcStore<MockStore>;
 
+/// Per-client counters recorded through the `ClientExecutionContext`
+/// observability hooks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockClientMetrics {
+    /// Number of successful client updates.
+    pub updates: u64,
+    /// Number of detected misbehaviours.
+    pub misbehaviours: u64,
+    /// Consensus height of the most recent successful update.
+    pub last_updated_height: Option<Height>,
+    /// Heights of the consensus states pruned so far, in pruning order.
+    pub pruned_heights: Vec<Height>,
+}
+
+/// A [`ConsensusStateCachePolicy`] retaining, past their expiry, up to
+/// `capacity` of the most recently accessed consensus states, e.g. the ones
+/// frequently referenced for packet verification. All other expired consensus
+/// states are evicted.
+#[derive(Debug, Default)]
+pub struct LruConsensusStatePolicy {
+    capacity: usize,
+    /// Accessed consensus states, from least to most recently accessed.
+    accesses: Mutex<Vec<(ClientId, Height)>>,
+}
+
+impl LruConsensusStatePolicy {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            accesses: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Records an access to the consensus state of `client_id` at `height`,
+    /// dropping the least recently accessed one if the policy is full.
+    pub fn record_access(&self, client_id: &ClientId, height: Height) {
+        let mut accesses = self.accesses.lock();
+
+        accesses.retain(|(id, h)| (id, *h) != (client_id, height));
+        accesses.push((client_id.clone(), height));
+
+        if accesses.len() > self.capacity {
+            let excess = accesses.len() - self.capacity;
+            accesses.drain(..excess);
+        }
+    }
+}
+
+impl ConsensusStateCachePolicy for LruConsensusStatePolicy {
+    fn should_evict(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+        meta: &ConsensusStateMeta,
+    ) -> bool {
+        meta.is_expired
+            && !self
+                .accesses
+                .lock()
+                .iter()
+                .any(|(id, h)| (id, *h) == (client_id, height))
+    }
+}
+
 /// An object that stores all IBC related data.
 #[derive(Debug)]
 pub struct MockIbcStore<S>
@@ -93,6 +162,24 @@ where
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// Per-client update and misbehaviour counters
+    pub client_metrics: Arc<Mutex<BTreeMap<ClientId, MockClientMetrics>>>,
+    /// Heights of the consensus states clients were created with
+    pub genesis_consensus_heights: Arc<Mutex<BTreeMap<ClientId, Height>>>,
+    /// Clients paused by an operator, which reject updates until unpaused
+    pub paused_clients: Arc<Mutex<BTreeSet<ClientId>>>,
+    /// Trusted next validator sets available locally, by client and height
+    pub trusted_next_validator_sets: Arc<Mutex<BTreeMap<(ClientId, Height), ValidatorSet>>>,
+    /// Whether clients may only be updated with adjacent headers
+    pub require_adjacent_updates: bool,
+    /// Maximum number of validators in the headers clients accept
+    pub max_header_validators: usize,
+    /// Policy consulted when pruning consensus states, if any
+    pub consensus_state_cache_policy: Option<LruConsensusStatePolicy>,
+    /// Whether packets without any timeout may be sent
+    pub allow_packets_without_timeout: bool,
+    /// Maximum length of the data of packets that may be sent
+    pub max_packet_data_len: usize,
 }
 
 impl<S> MockIbcStore<S>
@@ -140,6 +227,15 @@ where
             packet_ack_store: TypedStore::new(shared_store.clone()),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            client_metrics: Arc::new(Mutex::new(Default::default())),
+            genesis_consensus_heights: Arc::new(Mutex::new(Default::default())),
+            paused_clients: Arc::new(Mutex::new(Default::default())),
+            trusted_next_validator_sets: Arc::new(Mutex::new(Default::default())),
+            require_adjacent_updates: false,
+            max_header_validators: DEFAULT_MAX_HEADER_VALIDATORS,
+            consensus_state_cache_policy: None,
+            allow_packets_without_timeout: false,
+            max_packet_data_len: DEFAULT_MAX_PACKET_DATA_LEN,
             store: shared_store,
         }
     }
@@ -1,19 +1,24 @@
+use core::time::Duration;
+
 use basecoin_store::impls::InMemoryStore;
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ConsensusState as TmConsensusState,
 };
-use ibc::core::client::context::client_state::ClientStateCommon;
-use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc::core::client::context::{statuses, ClientValidationContext};
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
-use ibc::core::client::types::Height;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::error::CommitmentError;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath};
+use ibc::core::host::types::path::NextClientSequencePath;
 use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::Any;
 use ibc_query::core::context::ProvableContext;
 use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::clients::tendermint::{
@@ -21,6 +26,7 @@ use ibc_testkit::fixtures::clients::tendermint::{
 };
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
@@ -28,7 +34,9 @@ use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusStat
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientBuilder, MockIbcStore};
+use ibc_testkit::testapp::ibc::core::types::{
+    DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
+};
 use test_log::test;
 
 #[test]
@@ -52,10 +60,12 @@ fn test_create_client_ok() {
     let res = validate(&ctx, &router, msg_envelope.clone());
 
     assert!(res.is_ok(), "validation happy path");
+    assert!(!ctx.client_exists(&client_id));
 
     let res = execute(&mut ctx, &mut router, msg_envelope);
 
     assert!(res.is_ok(), "execution happy path");
+    assert!(ctx.client_exists(&client_id));
 
     let expected_client_state =
         ClientStateRef::<DefaultIbcStore>::try_from(msg.client_state).unwrap();
@@ -63,6 +73,134 @@ fn test_create_client_ok() {
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_genesis_consensus_height_retained_after_updates() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+    let signer = dummy_account_id();
+    let genesis_height = Height::new(0, 42).unwrap();
+    let genesis_timestamp = Timestamp::now();
+
+    let genesis_header = MockHeader::new(genesis_height).with_timestamp(genesis_timestamp);
+    let msg = MsgCreateClient::new(
+        MockClientState::new(genesis_header).into(),
+        MockConsensusState::new(genesis_header).into(),
+        signer.clone(),
+    );
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .expect("create client execution");
+
+    for blocks in 1..=3 {
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(genesis_height.add(blocks))
+                .with_timestamp((genesis_timestamp + Duration::from_secs(blocks)).unwrap())
+                .into(),
+            signer: signer.clone(),
+        };
+
+        execute(
+            &mut ctx,
+            &mut router,
+            MsgEnvelope::from(ClientMsg::from(msg)),
+        )
+        .expect("update client execution");
+    }
+
+    assert_eq!(
+        ctx.client_state(&client_id).unwrap().latest_height(),
+        genesis_height.add(3)
+    );
+    assert_eq!(
+        ctx.genesis_consensus_height(&client_id).unwrap(),
+        Some(genesis_height)
+    );
+}
+
+#[test]
+fn test_enumerate_created_clients() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+    let signer = dummy_account_id();
+    let client_type = mock_client_type();
+
+    let mut expected_client_ids = Vec::new();
+
+    for revision_height in [42, 43, 44] {
+        let height = Height::new(0, revision_height).unwrap();
+
+        let msg = MsgCreateClient::new(
+            MockClientState::new(MockHeader::new(height)).into(),
+            MockConsensusState::new(MockHeader::new(height)).into(),
+            signer.clone(),
+        );
+
+        expected_client_ids.push(client_type.build_client_id(ctx.client_counter().unwrap()));
+
+        execute(
+            &mut ctx,
+            &mut router,
+            MsgEnvelope::from(ClientMsg::from(msg)),
+        )
+        .expect("create client execution");
+    }
+
+    let mut client_ids = ctx.clients().unwrap();
+    client_ids.sort();
+    expected_client_ids.sort();
+
+    assert_eq!(client_ids, expected_client_ids);
+}
+
+#[test]
+fn test_statuses_of_clients() {
+    let active_client_id = mock_client_type().build_client_id(0);
+    let frozen_client_id = mock_client_type().build_client_id(1);
+    let missing_client_id = mock_client_type().build_client_id(2);
+
+    let latest_height = Height::new(0, 42).unwrap();
+
+    let mut frozen_light_client = LightClientState::<MockHost>::with_latest_height(latest_height);
+    frozen_light_client.client_state = frozen_light_client.client_state.frozen();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &active_client_id,
+            LightClientState::<MockHost>::with_latest_height(latest_height),
+        )
+        .with_light_client(&frozen_client_id, frozen_light_client);
+
+    let client_ids = [
+        active_client_id.clone(),
+        frozen_client_id.clone(),
+        missing_client_id.clone(),
+    ];
+
+    let statuses = statuses(&ctx.ibc_store, &client_ids);
+
+    assert_eq!(statuses.len(), 3);
+    assert_eq!(statuses[0].0, active_client_id);
+    assert_eq!(statuses[0].1.as_ref().unwrap(), &Status::Active);
+    assert_eq!(statuses[1].0, frozen_client_id);
+    assert_eq!(statuses[1].1.as_ref().unwrap(), &Status::Frozen);
+    assert_eq!(statuses[2].0, missing_client_id);
+    assert!(
+        matches!(
+            &statuses[2].1,
+            Err(ClientError::ClientStateNotFound { client_id }) if client_id == &missing_client_id
+        ),
+        "{:?}",
+        statuses[2].1
+    );
+}
+
 #[test]
 fn test_tm_create_client_ok() {
     let signer = dummy_account_id();
@@ -131,6 +269,59 @@ fn test_invalid_frozen_tm_client_creation() {
     ))
 }
 
+#[test]
+fn test_tm_initialise_validates_client_state() {
+    let mut ctx = DefaultIbcStore::default();
+
+    let tm_header = dummy_tendermint_header();
+    let consensus_state: Any = TmConsensusState::from(tm_header.clone()).into();
+    let client_id = tm_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    let valid_client_state = dummy_tm_client_state_from_header(tm_header);
+
+    // The trusting period must be shorter than the unbonding period.
+    let mut invalid_client_state = valid_client_state.inner().clone();
+    invalid_client_state.trusting_period = invalid_client_state.unbonding_period;
+    let invalid_client_state = TmClientState::from(invalid_client_state);
+
+    assert!(invalid_client_state
+        .initialise(&mut ctx, &client_id, consensus_state.clone())
+        .is_err());
+    assert!(ctx.client_state(&client_id).is_err());
+
+    valid_client_state
+        .initialise(&mut ctx, &client_id, consensus_state)
+        .expect("valid client state is stored");
+    assert!(ctx.client_state(&client_id).is_ok());
+}
+
+#[test]
+fn test_tm_initialise_requires_tendermint_client_id() {
+    let mut ctx = DefaultIbcStore::default();
+
+    let tm_header = dummy_tendermint_header();
+    let consensus_state: Any = TmConsensusState::from(tm_header.clone()).into();
+    let client_state = dummy_tm_client_state_from_header(tm_header);
+
+    let mismatched_client_id = mock_client_type().build_client_id(0);
+    match client_state.initialise(&mut ctx, &mismatched_client_id, consensus_state.clone()) {
+        Err(ClientError::ClientSpecific { description }) => {
+            assert!(
+                description.contains(mismatched_client_id.as_str()),
+                "{description}"
+            );
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+    assert!(ctx.client_state(&mismatched_client_id).is_err());
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    client_state
+        .initialise(&mut ctx, &client_id, consensus_state)
+        .expect("a Tendermint client id is accepted");
+    assert!(ctx.client_state(&client_id).is_ok());
+}
+
 #[test]
 fn test_tm_create_client_proof_verification_ok() {
     let client_id = ClientId::new("07-tendermint", 0).expect("no error");
@@ -155,11 +346,7 @@ fn test_tm_create_client_proof_verification_ok() {
     };
 
     let latest_client_height = tm_client_state.latest_height();
-    let consensus_state_path = ClientConsensusStatePath::new(
-        client_id.clone(),
-        latest_client_height.revision_number(),
-        latest_client_height.revision_height(),
-    );
+    let consensus_state_path = latest_client_height.consensus_state_path(&client_id);
 
     let AnyConsensusState::Tendermint(tm_consensus_state) = client_validation_ctx_mk
         .consensus_state(&consensus_state_path)
@@ -1,5 +1,6 @@
 #[cfg(feature = "serde")]
 pub mod create_client;
+pub mod proof_verification;
 pub mod recover_client;
 pub mod update_client;
 #[cfg(feature = "serde")]
@@ -0,0 +1,826 @@
+use core::time::Duration;
+
+use basecoin_store::avl::get_proof_spec as basecoin_proof_spec;
+use basecoin_store::context::Store;
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::types::ClientState as TmClientStateType;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{CommitmentProof, NonExistenceProof};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::connection::proof_paths::connection_handshake_proof_paths;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientStatePath, CommitmentPath, ConnectionPath, Path, ReceiptPath,
+    SeqRecvPath, UpgradeClientPath,
+};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::ZERO_DURATION;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::{Timestamp, ToVec};
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_tendermint_header, dummy_tm_client_state_from_header,
+};
+use ibc_testkit::hosts::TendermintHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
+use rstest::*;
+
+/// Holds a Tendermint light client tracking `ctx`, together with the root
+/// and prefix against which proofs from `ctx` at its latest height verify.
+struct Fixture {
+    ctx: TendermintContext,
+    client_state: TmClientState,
+    root: CommitmentRoot,
+    prefix: CommitmentPrefix,
+    port_id: PortId,
+    chan_id: ChannelId,
+    chan_end: ChannelEnd,
+    conn_id: ConnectionId,
+    conn_end: ConnectionEnd,
+    client_id: ClientId,
+    /// Sequence of a packet for which `ctx` stores a receipt
+    received_seq: Sequence,
+    /// Sequence of a packet for which `ctx` stores no receipt, in between
+    /// `received_seq` and the sequence of another packet it stores a receipt
+    /// for
+    unreceived_seq: Sequence,
+    /// Sequence of a packet for which `ctx` stores `packet_commitment`
+    sent_seq: Sequence,
+    packet_commitment: PacketCommitment,
+    /// Next receive sequence `ctx` stores for the channel, encoded as the
+    /// Cosmos SDK does
+    next_seq_recv: Sequence,
+    /// Upgraded client state `ctx` stores under the upgrade path at
+    /// `upgrade_key_height`
+    upgraded_client_state: Any,
+    upgrade_key_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let conn_id = ConnectionId::new(0);
+    let client_id = mock_client_type().build_client_id(0);
+    let received_seq = Sequence::from(1);
+    let unreceived_seq = Sequence::from(2);
+    let next_seq_recv = Sequence::from(2);
+    let sent_seq = Sequence::from(3);
+    let packet_commitment = compute_packet_commitment(
+        b"packet data",
+        &TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        &Timestamp::none(),
+    );
+    let upgrade_key_height = 1;
+    let upgraded_client_state: Any =
+        dummy_tm_client_state_from_header(dummy_tendermint_header()).into();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(conn_id.clone()),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .expect("no error");
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        ChannelVersion::new("ics20-1".to_string()),
+    )
+    .expect("no error");
+
+    let ctx_b = MockContext::default();
+
+    let mut ctx = TendermintContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init().context(&ctx_b).build(),
+        )
+        .with_connection(conn_id.clone(), conn_end.clone())
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end.clone());
+
+    for seq in [received_seq, unreceived_seq.increment()] {
+        ctx.ibc_store_mut()
+            .store_packet_receipt(&ReceiptPath::new(&port_id, &chan_id, seq), Receipt::Ok)
+            .expect("no error");
+    }
+
+    ctx.ibc_store_mut()
+        .store_packet_commitment(
+            &CommitmentPath::new(&port_id, &chan_id, sent_seq),
+            packet_commitment.clone(),
+        )
+        .expect("no error");
+
+    // The mock store encodes sequences as JSON, so the big-endian encoding
+    // is written directly instead.
+    ctx.ibc_store_mut()
+        .store
+        .set(
+            Path::SeqRecv(SeqRecvPath::new(&port_id, &chan_id))
+                .to_string()
+                .into(),
+            next_seq_recv.to_vec(),
+        )
+        .expect("no error");
+
+    ctx.ibc_store_mut()
+        .store
+        .set(
+            Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(upgrade_key_height))
+                .to_string()
+                .into(),
+            upgraded_client_state.to_vec(),
+        )
+        .expect("no error");
+
+    ctx.advance_block_height();
+
+    let proof_height = ctx.latest_height();
+
+    let LightClientState {
+        client_state,
+        consensus_states,
+    }: LightClientState<TendermintHost> = LightClientBuilder::init().context(&ctx).build();
+
+    let consensus_state: &TmConsensusState = consensus_states
+        .get(&proof_height)
+        .expect("consensus state exists");
+
+    Fixture {
+        root: consensus_state.root().clone(),
+        prefix: ctx.ibc_store().commitment_prefix(),
+        ctx,
+        client_state,
+        port_id,
+        chan_id,
+        chan_end,
+        conn_id,
+        conn_end,
+        client_id,
+        received_seq,
+        unreceived_seq,
+        sent_seq,
+        packet_commitment,
+        next_seq_recv,
+        upgraded_client_state,
+        upgrade_key_height,
+    }
+}
+
+fn proof_at_latest_height(ctx: &TendermintContext, path: Path) -> CommitmentProofBytes {
+    ctx.ibc_store()
+        .get_proof(ctx.latest_height(), &path)
+        .expect("value exists")
+        .try_into()
+        .expect("value merkle proof")
+}
+
+/// Assembles a proof that nothing is stored under `path` in the IBC store of
+/// `ctx` at its latest height, as the store itself only produces existence
+/// proofs.
+///
+/// `left` and `right` must be the stored keys immediately preceding and
+/// following `path`, where `right` is `None` if `path` comes after every
+/// stored key.
+fn non_membership_proof_at_latest_height(
+    ctx: &TendermintContext,
+    path: Path,
+    left: Path,
+    right: Option<Path>,
+) -> CommitmentProofBytes {
+    let existence_proof = |path: Path| {
+        let MerkleProof { proofs } =
+            MerkleProof::try_from(&proof_at_latest_height(ctx, path)).expect("no error");
+        let [path_proof, ibc_commitment_proof]: [CommitmentProof; 2] =
+            proofs.try_into().expect("path and ibc commitment proofs");
+        let Some(Proof::Exist(existence_proof)) = path_proof.proof else {
+            panic!("existence proof");
+        };
+        (existence_proof, ibc_commitment_proof)
+    };
+
+    let (left, ibc_commitment_proof) = existence_proof(left);
+    let right = right.map(|right| existence_proof(right).0);
+
+    MerkleProof {
+        proofs: vec![
+            CommitmentProof {
+                proof: Some(Proof::Nonexist(NonExistenceProof {
+                    key: path.to_string().into_bytes(),
+                    left: Some(left),
+                    right,
+                })),
+            },
+            ibc_commitment_proof,
+        ],
+    }
+    .try_into_commitment_proof_bytes()
+    .expect("no error")
+}
+
+#[rstest]
+fn test_verify_channel_state(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        chan_end,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::ChannelEnd(ChannelEndPath::new(&port_id, &chan_id)),
+    );
+
+    client_state
+        .verify_channel_state(&prefix, &proof, &root, &port_id, &chan_id, &chan_end)
+        .expect("channel proof verifies");
+
+    let mut unexpected_chan_end = chan_end.clone();
+    unexpected_chan_end.set_state(State::Closed);
+
+    assert!(client_state
+        .verify_channel_state(
+            &prefix,
+            &proof,
+            &root,
+            &port_id,
+            &chan_id,
+            &unexpected_chan_end
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_connection_state(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        conn_id,
+        conn_end,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(&ctx, Path::Connection(ConnectionPath::new(&conn_id)));
+
+    client_state
+        .verify_connection_state(&prefix, &proof, &root, &conn_id, &conn_end)
+        .expect("connection proof verifies");
+
+    let mut unexpected_conn_end = conn_end.clone();
+    unexpected_conn_end.set_state(ConnectionState::Init);
+
+    assert!(client_state
+        .verify_connection_state(&prefix, &proof, &root, &conn_id, &unexpected_conn_end)
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_client_state(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        client_id,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::ClientState(ClientStatePath::new(client_id.clone())),
+    );
+
+    let stored_client_state: Any = ctx
+        .ibc_store()
+        .client_state(&client_id)
+        .expect("client state exists")
+        .into();
+
+    client_state
+        .verify_client_state(&prefix, &proof, &root, &client_id, stored_client_state)
+        .expect("client state proof verifies");
+
+    assert!(client_state
+        .verify_client_state(
+            &prefix,
+            &proof,
+            &root,
+            &client_id,
+            client_state.clone().into()
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_membership_of_client_state_commitment_bytes(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        upgraded_client_state,
+        upgrade_key_height,
+        ..
+    } = fixture;
+
+    // `ctx` stores the Any-encoded upgraded client state, as hosts do for
+    // client states.
+    let path = Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(upgrade_key_height));
+    let proof = proof_at_latest_height(&ctx, path.clone());
+
+    let stored_client_state =
+        TmClientStateType::try_from(upgraded_client_state.clone()).expect("no error");
+    let commitment_bytes = stored_client_state.commitment_bytes();
+    assert_eq!(commitment_bytes, upgraded_client_state.to_vec());
+
+    client_state
+        .verify_membership(&prefix, &proof, &root, path.clone(), commitment_bytes)
+        .expect("client state commitment proof verifies");
+
+    let mut unexpected_client_state = stored_client_state;
+    unexpected_client_state.trusting_period += Duration::from_secs(1);
+    assert!(client_state
+        .verify_membership(
+            &prefix,
+            &proof,
+            &root,
+            path,
+            unexpected_client_state.commitment_bytes()
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_packet_receipt_absence(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        received_seq,
+        unreceived_seq,
+        ..
+    } = fixture;
+
+    let proof = non_membership_proof_at_latest_height(
+        &ctx,
+        Path::Receipt(ReceiptPath::new(&port_id, &chan_id, unreceived_seq)),
+        Path::Receipt(ReceiptPath::new(&port_id, &chan_id, received_seq)),
+        Some(Path::Receipt(ReceiptPath::new(
+            &port_id,
+            &chan_id,
+            unreceived_seq.increment(),
+        ))),
+    );
+
+    client_state
+        .verify_packet_receipt_absence(&prefix, &proof, &root, &port_id, &chan_id, unreceived_seq)
+        .expect("receipt absence proof verifies");
+
+    assert!(client_state
+        .verify_packet_receipt_absence(&prefix, &proof, &root, &port_id, &chan_id, received_seq)
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_packet_receipt_absence_rejects_existing_receipt(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        received_seq,
+        unreceived_seq,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::Receipt(ReceiptPath::new(&port_id, &chan_id, received_seq)),
+    );
+
+    assert!(client_state
+        .verify_packet_receipt_absence(&prefix, &proof, &root, &port_id, &chan_id, received_seq)
+        .is_err());
+
+    assert!(client_state
+        .verify_packet_receipt_absence(&prefix, &proof, &root, &port_id, &chan_id, unreceived_seq)
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_packet_commitment(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        sent_seq,
+        packet_commitment,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::Commitment(CommitmentPath::new(&port_id, &chan_id, sent_seq)),
+    );
+
+    client_state
+        .verify_packet_commitment(
+            &prefix,
+            &proof,
+            &root,
+            &port_id,
+            &chan_id,
+            sent_seq,
+            packet_commitment.into_vec(),
+        )
+        .expect("packet commitment proof verifies");
+
+    let unexpected_commitment = compute_packet_commitment(
+        b"other packet data",
+        &TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        &Timestamp::none(),
+    );
+
+    assert!(client_state
+        .verify_packet_commitment(
+            &prefix,
+            &proof,
+            &root,
+            &port_id,
+            &chan_id,
+            sent_seq,
+            unexpected_commitment.into_vec()
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_next_sequence_recv(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        next_seq_recv,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(&ctx, Path::SeqRecv(SeqRecvPath::new(&port_id, &chan_id)));
+
+    client_state
+        .verify_next_sequence_recv(&prefix, &proof, &root, &port_id, &chan_id, next_seq_recv)
+        .expect("next sequence receive proof verifies");
+
+    assert!(client_state
+        .verify_next_sequence_recv(
+            &prefix,
+            &proof,
+            &root,
+            &port_id,
+            &chan_id,
+            next_seq_recv.increment()
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_is_upgrade_scheduled(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        upgraded_client_state,
+        upgrade_key_height,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(upgrade_key_height)),
+    );
+
+    // The IBC store of `ctx` serves as the upgrade store.
+    let with_upgrade_path = |upgrade_path: Vec<String>| {
+        let mut client_state = client_state.inner().clone();
+        client_state.latest_height = Height::new(
+            client_state.latest_height.revision_number(),
+            upgrade_key_height,
+        )
+        .expect("no error");
+        client_state.upgrade_path = upgrade_path;
+        TmClientState::from(client_state)
+    };
+    let client_state = with_upgrade_path(vec!["ibc".to_string(), "upgradedIBCState".to_string()]);
+
+    assert!(client_state
+        .is_upgrade_scheduled(&proof, &root)
+        .expect("upgrade proof verifies"));
+
+    // The upgrade is scheduled, but not to the claimed client state.
+    let mut unexpected_client_state =
+        TmClientStateType::try_from(upgraded_client_state).expect("no error");
+    unexpected_client_state.trusting_period += Duration::from_secs(1);
+    assert!(client_state
+        .verify_upgrade_client(
+            TmClientState::from(unexpected_client_state).into(),
+            TmConsensusState::from(dummy_tendermint_header()).into(),
+            proof.clone(),
+            proof.clone(),
+            &root,
+        )
+        .is_err());
+
+    // No upgrade can be scheduled for a client without an upgrade path.
+    assert!(!with_upgrade_path(Vec::new())
+        .is_upgrade_scheduled(&proof, &root)
+        .expect("no error"));
+
+    // An upgrade path without a store key is invalid.
+    assert!(with_upgrade_path(vec!["ibc".to_string()])
+        .is_upgrade_scheduled(&proof, &root)
+        .is_err());
+
+    // No upgraded client state is stored for the next height, which is
+    // proven with a non-membership proof. The upgraded client state stored
+    // for `upgrade_key_height` is the last key of the store.
+    let mut unscheduled_client_state = client_state.inner().clone();
+    unscheduled_client_state.latest_height = unscheduled_client_state.latest_height.increment();
+    let unscheduled_client_state = TmClientState::from(unscheduled_client_state);
+
+    let absence_proof = non_membership_proof_at_latest_height(
+        &ctx,
+        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(
+            upgrade_key_height + 1,
+        )),
+        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(upgrade_key_height)),
+        None,
+    );
+
+    assert!(!unscheduled_client_state
+        .is_upgrade_scheduled(&absence_proof, &root)
+        .expect("absence proof verifies"));
+
+    // The absence proof does not apply to the scheduled upgrade.
+    assert!(client_state
+        .is_upgrade_scheduled(&absence_proof, &root)
+        .is_err());
+}
+
+#[rstest]
+fn test_verify_client_consensus_state(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        client_id,
+        ..
+    } = fixture;
+
+    let consensus_height = ctx
+        .ibc_store()
+        .client_state(&client_id)
+        .expect("client state exists")
+        .latest_height();
+    let consensus_state_path = consensus_height.consensus_state_path(&client_id);
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::ClientConsensusState(consensus_state_path.clone()),
+    );
+
+    let stored_consensus_state: Any = ctx
+        .ibc_store()
+        .consensus_state(&consensus_state_path)
+        .expect("consensus state exists")
+        .into();
+
+    client_state
+        .verify_client_consensus_state(
+            &prefix,
+            &proof,
+            &root,
+            &client_id,
+            consensus_height,
+            stored_consensus_state.clone(),
+        )
+        .expect("consensus state proof verifies");
+
+    let mismatched_consensus_state = Any {
+        value: b"not the consensus state".to_vec(),
+        ..stored_consensus_state
+    };
+
+    assert!(client_state
+        .verify_client_consensus_state(
+            &prefix,
+            &proof,
+            &root,
+            &client_id,
+            consensus_height,
+            mismatched_consensus_state,
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_connection_handshake_proof_paths(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        conn_id,
+        conn_end,
+        client_id,
+        ..
+    } = fixture;
+
+    let consensus_height = ctx
+        .ibc_store()
+        .client_state(&client_id)
+        .expect("client state exists")
+        .latest_height();
+
+    let paths = connection_handshake_proof_paths(&client_id, &conn_id, consensus_height);
+
+    let consensus_state_path = consensus_height.consensus_state_path(&client_id);
+    let expected_values: Vec<(Path, Vec<u8>)> = vec![
+        (
+            Path::Connection(ConnectionPath::new(&conn_id)),
+            conn_end.encode_vec(),
+        ),
+        (
+            Path::ClientState(ClientStatePath::new(client_id.clone())),
+            Any::from(ctx.ibc_store().client_state(&client_id).expect("no error")).to_vec(),
+        ),
+        (
+            Path::ClientConsensusState(consensus_state_path.clone()),
+            Any::from(
+                ctx.ibc_store()
+                    .consensus_state(&consensus_state_path)
+                    .expect("no error"),
+            )
+            .to_vec(),
+        ),
+    ];
+
+    assert_eq!(
+        paths,
+        expected_values
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>()
+    );
+
+    // The counterparty stores, and can thus prove, the expected values under
+    // all of the paths.
+    for (path, value) in expected_values {
+        let proof = proof_at_latest_height(&ctx, path.clone());
+
+        client_state
+            .verify_membership(&prefix, &proof, &root, path, value)
+            .expect("handshake proof verifies");
+    }
+}
+
+#[rstest]
+fn test_explain_membership_failure(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        conn_id,
+        conn_end,
+        ..
+    } = fixture;
+
+    let path = Path::Connection(ConnectionPath::new(&conn_id));
+    let proof = proof_at_latest_height(&ctx, path.clone());
+
+    let explanation = client_state.explain_membership_failure(
+        &prefix,
+        &proof,
+        &root,
+        path.clone(),
+        conn_end.encode_vec(),
+    );
+    assert_eq!(explanation, "membership proof verifies");
+
+    let explanation = client_state.explain_membership_failure(
+        &prefix,
+        &proof,
+        &root,
+        path.clone(),
+        b"not the connection end".to_vec(),
+    );
+    assert!(explanation.starts_with("value mismatch"), "{explanation}");
+
+    // A client only knowing of the spec of the IBC store, whereas the proof
+    // also covers the multi store
+    let mut single_spec_client_state = client_state.inner().clone();
+    single_spec_client_state.proof_specs =
+        ProofSpecs::try_from(vec![basecoin_proof_spec()]).expect("no error");
+
+    let explanation = TmClientState::from(single_spec_client_state).explain_membership_failure(
+        &prefix,
+        &proof,
+        &root,
+        path,
+        conn_end.encode_vec(),
+    );
+    assert!(
+        explanation.starts_with("likely proof-spec mismatch"),
+        "{explanation}"
+    );
+}
+
+#[rstest]
+fn test_verify_membership_rejects_reversed_proof_specs(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        client_state,
+        root,
+        prefix,
+        port_id,
+        chan_id,
+        chan_end,
+        ..
+    } = fixture;
+
+    let proof = proof_at_latest_height(
+        &ctx,
+        Path::ChannelEnd(ChannelEndPath::new(&port_id, &chan_id)),
+    );
+
+    // The proof consists of the path proof within the IBC store, which has
+    // inner nodes, followed by the proof of the IBC store within the multi
+    // store, which holds a single leaf. Requiring at least one inner node
+    // thus distinguishes the two specs.
+    let mut store_spec = basecoin_proof_spec();
+    store_spec.min_depth = 1;
+    store_spec.max_depth = 64;
+
+    let specs = ProofSpecs::try_from(vec![store_spec, basecoin_proof_spec()]).expect("no error");
+
+    let with_specs = |proof_specs: ProofSpecs| {
+        let mut client_state = client_state.inner().clone();
+        client_state.proof_specs = proof_specs;
+        TmClientState::from(client_state)
+    };
+
+    with_specs(specs.clone())
+        .verify_channel_state(&prefix, &proof, &root, &port_id, &chan_id, &chan_end)
+        .expect("channel proof verifies");
+
+    assert!(with_specs(specs.reversed())
+        .verify_channel_state(&prefix, &proof, &root, &port_id, &chan_id, &chan_end)
+        .is_err());
+}
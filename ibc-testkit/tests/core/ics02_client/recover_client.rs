@@ -1,24 +1,31 @@
 use core::time::Duration;
 
-use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::context::ClientValidationContext;
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
 use ibc::core::client::handler::recover_client;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgRecoverClient};
 use ibc::core::client::types::{Height, Status as ClientStatus};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::path::ClientStatePath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::Signer;
 use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
-use ibc_testkit::hosts::{TestBlock, TestHost};
+use ibc_testkit::hosts::{TendermintHost, TestBlock, TestHost};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
-use ibc_testkit::testapp::ibc::core::types::DEFAULT_BLOCK_TIME_SECS;
+use ibc_testkit::testapp::ibc::core::types::{
+    LightClientBuilder, LightClientState, DEFAULT_BLOCK_TIME_SECS,
+};
 use rstest::*;
 
 struct Fixture {
@@ -177,18 +184,10 @@ fn test_recover_client_ok() {
     // latest consensus state is copied.
     assert_eq!(
         ctx.ibc_store()
-            .consensus_state(&ClientConsensusStatePath::new(
-                msg.subject_client_id,
-                substitute_height.revision_number(),
-                substitute_height.revision_height(),
-            ))
+            .consensus_state(&substitute_height.consensus_state_path(&msg.subject_client_id))
             .unwrap(),
         ctx.ibc_store()
-            .consensus_state(&ClientConsensusStatePath::new(
-                msg.substitute_client_id,
-                substitute_height.revision_number(),
-                substitute_height.revision_height(),
-            ))
+            .consensus_state(&substitute_height.consensus_state_path(&msg.substitute_client_id))
             .unwrap(),
     );
 }
@@ -287,3 +286,152 @@ fn test_recover_client_with_matching_heights() {
     recover_client::validate(ctx.ibc_store(), msg)
         .expect_err("expected client recovery validation to fail");
 }
+
+/// Sets up a context hosting a frozen Tendermint subject client and an active
+/// Tendermint substitute client with consensus states at two greater heights,
+/// whose client state is created with the given `substitute_params`.
+fn setup_tm_substitute_fixture(
+    substitute_params: ClientStateConfig,
+) -> (MockContext, TmClientState, ClientId, ClientId) {
+    let subject_client_id = tm_client_type().build_client_id(0);
+    let substitute_client_id = tm_client_type().build_client_id(1);
+
+    let subject_height = Height::new(1, 10).unwrap();
+    let substitute_earlier_height = Height::new(1, 15).unwrap();
+    let substitute_height = Height::new(1, 20).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(substitute_height)
+        .build::<TendermintContext>();
+
+    let subject_light_client: LightClientState<TendermintHost> = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([subject_height])
+        .build();
+
+    let frozen_subject_client_state = TmClientState::from(
+        subject_light_client
+            .client_state
+            .inner()
+            .clone()
+            .with_frozen_height(Height::min(0)),
+    );
+
+    let mut ctx = MockContext::default()
+        .with_light_client(&subject_client_id, subject_light_client)
+        .with_light_client(
+            &substitute_client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([substitute_earlier_height, substitute_height])
+                .params(substitute_params)
+                .build(),
+        );
+
+    ctx.ibc_store_mut()
+        .store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            frozen_subject_client_state.clone().into(),
+        )
+        .expect("no error");
+
+    assert!(ctx
+        .ibc_store()
+        .client_state(&subject_client_id)
+        .expect("subject client state exists")
+        .status(ctx.ibc_store(), &subject_client_id)
+        .expect("no error")
+        .is_frozen());
+
+    (
+        ctx,
+        frozen_subject_client_state,
+        subject_client_id,
+        substitute_client_id,
+    )
+}
+
+#[rstest]
+fn test_check_substitute_and_update_state_ok() {
+    let (mut ctx, subject_client_state, subject_client_id, substitute_client_id) =
+        setup_tm_substitute_fixture(ClientStateConfig::builder().build());
+
+    subject_client_state
+        .check_substitute_and_update_state(
+            ctx.ibc_store_mut(),
+            &subject_client_id,
+            &substitute_client_id,
+        )
+        .expect("substitute matches the subject client");
+
+    let subject_client_state = ctx
+        .ibc_store()
+        .client_state(&subject_client_id)
+        .expect("subject client state exists");
+    let substitute_client_state = ctx
+        .ibc_store()
+        .client_state(&substitute_client_id)
+        .expect("substitute client state exists");
+
+    // subject client is unfrozen.
+    assert_eq!(
+        subject_client_state
+            .status(ctx.ibc_store(), &subject_client_id)
+            .expect("no error"),
+        ClientStatus::Active
+    );
+
+    // latest height is copied.
+    let substitute_height = substitute_client_state.latest_height();
+    assert_eq!(subject_client_state.latest_height(), substitute_height);
+
+    // all the substitute's consensus states are copied.
+    let substitute_heights = ctx
+        .ibc_store()
+        .consensus_state_heights(&substitute_client_id)
+        .expect("no error");
+    assert_eq!(substitute_heights.len(), 2);
+
+    for height in substitute_heights {
+        assert_eq!(
+            ctx.ibc_store()
+                .consensus_state(&height.consensus_state_path(&subject_client_id))
+                .unwrap(),
+            ctx.ibc_store()
+                .consensus_state(&height.consensus_state_path(&substitute_client_id))
+                .unwrap(),
+        );
+    }
+}
+
+#[rstest]
+fn test_check_substitute_and_update_state_with_mismatched_params() {
+    let (mut ctx, subject_client_state, subject_client_id, substitute_client_id) =
+        setup_tm_substitute_fixture(
+            ClientStateConfig::builder()
+                .unbonding_period(Duration::from_secs(256_000))
+                .build(),
+        );
+
+    subject_client_state
+        .check_substitute_and_update_state(
+            ctx.ibc_store_mut(),
+            &subject_client_id,
+            &substitute_client_id,
+        )
+        .expect_err("substitute with a different unbonding period must be rejected");
+
+    // subject client remains frozen.
+    assert!(ctx
+        .ibc_store()
+        .client_state(&subject_client_id)
+        .expect("subject client state exists")
+        .status(ctx.ibc_store(), &subject_client_id)
+        .expect("no error")
+        .is_frozen());
+}
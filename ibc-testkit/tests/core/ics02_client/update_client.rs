@@ -1,31 +1,44 @@
 use core::fmt::Debug;
 use core::str::FromStr;
 use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
 
-use basecoin_store::context::ProvableStore;
-use ibc::clients::tendermint::client_state::ClientState;
+use basecoin_store::context::{ProvableStore, Store};
+use basecoin_store::types::Height as StoreHeight;
+use ibc::clients::tendermint::client_state::{
+    check_for_misbehaviour_on_update, prune_oldest_consensus_state, verify_header, ClientState,
+};
+use ibc::clients::tendermint::types::error::AuditError;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
+    client_type as tm_client_type, AllowUpdate, ClientState as TmClientState,
+    ConsensusState as TmConsensusStateType, FrozenHeight, Header as TmHeader,
     Misbehaviour as TmMisbehaviour,
 };
-use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::path::{ClientConsensusStatePath, Path};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::context::{MockContext, TendermintContext, TestContext};
-use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_header_with_validators, ClientStateConfig, HeaderVerificationSetup,
+};
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::tendermint::BlockParams;
@@ -41,11 +54,17 @@ use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as Mock
 use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
-    DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
+    DefaultIbcStore, LightClientBuilder, LightClientState, LruConsensusStatePolicy, MockIbcStore,
 };
 use rstest::*;
+use tendermint::crypto::default::Sha256;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::Hash;
+use tendermint_light_client_verifier::ProdVerifier;
 use tendermint_testgen::Validator as TestgenValidator;
-use tracing::debug;
+use tracing::field::{Field, Visit};
+use tracing::{debug, span};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
 
 struct Fixture {
     ctx: MockContext,
@@ -198,11 +217,7 @@ fn test_update_client_with_prev_header() {
     let client_state = ctx.client_state(&client_id).unwrap();
     assert_eq!(client_state.latest_height(), height_2);
 
-    let cons_state_path = ClientConsensusStatePath::new(
-        client_id,
-        height_1.revision_number(),
-        height_1.revision_height(),
-    );
+    let cons_state_path = height_1.consensus_state_path(&client_id);
     assert!(ctx.consensus_state(&cons_state_path).is_ok());
 }
 
@@ -250,7 +265,7 @@ fn test_consensus_state_pruning() {
 
     let mut router = MockRouter::new_with_transfer();
 
-    let start_host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
+    let start_host_timestamp = ValidationContext::host_timestamp(&ctx.ibc_store).unwrap();
 
     // Move the chain forward by 2 blocks to pass the trusting period.
     for _ in 1..=2 {
@@ -279,11 +294,7 @@ fn test_consensus_state_pruning() {
 
     // Check that latest expired consensus state is pruned.
     let expired_height = Height::new(1, 1).unwrap();
-    let client_cons_state_path = ClientConsensusStatePath::new(
-        client_id.clone(),
-        expired_height.revision_number(),
-        expired_height.revision_height(),
-    );
+    let client_cons_state_path = expired_height.consensus_state_path(&client_id);
     assert!(ctx
         .ibc_store
         .client_update_meta(&client_id, &expired_height)
@@ -293,13 +304,15 @@ fn test_consensus_state_pruning() {
         .consensus_state(&client_cons_state_path)
         .is_err());
 
+    // Check that the pruned height is reported to the host.
+    assert_eq!(
+        ctx.ibc_store.client_metrics.lock()[&client_id].pruned_heights,
+        vec![expired_height]
+    );
+
     // Check that latest valid consensus state exists.
     let earliest_valid_height = Height::new(1, 2).unwrap();
-    let client_cons_state_path = ClientConsensusStatePath::new(
-        client_id.clone(),
-        earliest_valid_height.revision_number(),
-        earliest_valid_height.revision_height(),
-    );
+    let client_cons_state_path = earliest_valid_height.consensus_state_path(&client_id);
 
     assert!(ctx
         .ibc_store
@@ -310,7 +323,7 @@ fn test_consensus_state_pruning() {
         .consensus_state(&client_cons_state_path)
         .is_ok());
 
-    let end_host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
+    let end_host_timestamp = ValidationContext::host_timestamp(&ctx.ibc_store).unwrap();
 
     assert_eq!(
         end_host_timestamp,
@@ -318,34 +331,1556 @@ fn test_consensus_state_pruning() {
     );
 }
 
+#[rstest]
+fn test_consensus_state_pruning_with_lru_policy() {
+    let client_id = tm_client_type().build_client_id(0);
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let heights = [1, 2, 3].map(|h| Height::new(1, h).unwrap());
+
+    // Blocks are 3 seconds apart, so that once the host moves one block past
+    // `ctx_b`, the consensus states at heights 1 and 2 are expired.
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(heights[2])
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights(heights)
+        .params(
+            ClientStateConfig::builder()
+                .trusting_period(Duration::from_secs(4))
+                .build(),
+        )
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    ctx.advance_block_height();
+
+    let policy = LruConsensusStatePolicy::new(1);
+    policy.record_access(&client_id, heights[1]);
+    ctx.ibc_store.consensus_state_cache_policy = Some(policy);
+
+    let pruned_heights =
+        prune_oldest_consensus_state(client_state.inner(), &mut ctx.ibc_store, &client_id)
+            .expect("pruning succeeds");
+
+    // The accessed consensus state is retained, unlike the older one.
+    assert_eq!(pruned_heights, vec![heights[0]]);
+
+    for (height, retained) in heights.into_iter().zip([false, true, true]) {
+        assert_eq!(
+            ctx.ibc_store
+                .consensus_state(&height.consensus_state_path(&client_id))
+                .is_ok(),
+            retained,
+            "consensus state at {height}"
+        );
+    }
+
+    // Once no longer among the recently accessed ones, it is evicted too.
+    ctx.ibc_store
+        .consensus_state_cache_policy
+        .as_ref()
+        .unwrap()
+        .record_access(&client_id, heights[2]);
+
+    let pruned_heights =
+        prune_oldest_consensus_state(client_state.inner(), &mut ctx.ibc_store, &client_id)
+            .expect("pruning succeeds");
+
+    assert_eq!(pruned_heights, vec![heights[1]]);
+}
+
+#[rstest]
+fn test_count_prunable_matches_pruning() {
+    let client_id = tm_client_type().build_client_id(0);
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let heights = [1, 2, 3].map(|h| Height::new(1, h).unwrap());
+
+    // Blocks are 3 seconds apart, so that once the host moves one block past
+    // `ctx_b`, the consensus states at heights 1 and 2 are expired.
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(heights[2])
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights(heights)
+        .params(
+            ClientStateConfig::builder()
+                .trusting_period(Duration::from_secs(4))
+                .build(),
+        )
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    ctx.advance_block_height();
+
+    let now = ValidationContext::host_timestamp(&ctx.ibc_store).unwrap();
+
+    let prunable = client_state
+        .count_prunable(&ctx.ibc_store, &client_id, now)
+        .expect("counting succeeds");
+
+    assert_eq!(prunable, 2);
+
+    // Counting leaves all the consensus states in place.
+    for height in heights {
+        assert!(ctx
+            .ibc_store
+            .consensus_state(&height.consensus_state_path(&client_id))
+            .is_ok());
+    }
+
+    let pruned_heights =
+        prune_oldest_consensus_state(client_state.inner(), &mut ctx.ibc_store, &client_id)
+            .expect("pruning succeeds");
+
+    assert_eq!(pruned_heights.len(), prunable);
+}
+
+#[rstest]
+fn test_oldest_consensus_state_height() {
+    let chain_id = ChainId::new("mockgaiaA-1").unwrap();
+    let client_id = tm_client_type().build_client_id(0);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id.clone()).build())
+        .latest_height(Height::new(1, 10).unwrap())
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id).build())
+        .build::<TendermintContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([
+                    Height::new(1, 10).unwrap(),
+                    Height::new(1, 2).unwrap(),
+                    Height::new(1, 5).unwrap(),
+                ])
+                .build(),
+        );
+
+    assert_eq!(
+        ctx.ibc_store
+            .oldest_consensus_state_height(&client_id)
+            .unwrap(),
+        Some(Height::new(1, 2).unwrap())
+    );
+    assert_eq!(
+        ctx.ibc_store
+            .oldest_consensus_state_height(&tm_client_type().build_client_id(1))
+            .unwrap(),
+        None
+    );
+}
+
+#[rstest]
+fn test_audit_consensus_history() {
+    let chain_id = ChainId::new("mockgaiaA-1").unwrap();
+    let client_id = tm_client_type().build_client_id(0);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id.clone()).build())
+        .latest_height(Height::new(1, 10).unwrap())
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([
+            Height::new(1, 2).unwrap(),
+            Height::new(1, 5).unwrap(),
+            Height::new(1, 10).unwrap(),
+        ])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id).build())
+        .build::<TendermintContext>()
+        .with_light_client(&client_id, light_client);
+
+    client_state
+        .audit_consensus_history(&ctx.ibc_store, &client_id)
+        .expect("consistent history");
+
+    let path_at = |revision_number, revision_height| {
+        ClientConsensusStatePath::new(client_id.clone(), revision_number, revision_height)
+    };
+    let shifted_consensus_state =
+        |ibc_store: &DefaultIbcStore, path: ClientConsensusStatePath, offset: i64| {
+            let AnyConsensusState::Tendermint(consensus_state) =
+                ibc_store.consensus_state(&path).unwrap()
+            else {
+                panic!("tendermint consensus state");
+            };
+            let timestamp = consensus_state.inner().timestamp;
+            let timestamp = if offset < 0 {
+                timestamp - Duration::from_secs(offset.unsigned_abs())
+            } else {
+                timestamp + Duration::from_secs(offset.unsigned_abs())
+            };
+            AnyConsensusState::from(TmConsensusStateType {
+                timestamp: timestamp.unwrap(),
+                ..consensus_state.inner().clone()
+            })
+        };
+
+    // Consensus states from before an upgrade of the chain are of a lower
+    // revision, which is consistent.
+    let pre_upgrade_consensus_state = shifted_consensus_state(&ctx.ibc_store, path_at(1, 2), -1);
+    ctx.ibc_store
+        .store_consensus_state(path_at(0, 20), pre_upgrade_consensus_state)
+        .unwrap();
+
+    client_state
+        .audit_consensus_history(&ctx.ibc_store, &client_id)
+        .expect("consistent history across an upgrade");
+
+    // A consensus state of a revision above that of the chain id is not.
+    let future_consensus_state = shifted_consensus_state(&ctx.ibc_store, path_at(1, 10), 1);
+    ctx.ibc_store
+        .store_consensus_state(path_at(2, 1), future_consensus_state)
+        .unwrap();
+
+    let res = client_state.audit_consensus_history(&ctx.ibc_store, &client_id);
+    match res {
+        Err(AuditError::RevisionMismatch { height, .. }) => {
+            assert_eq!(height, Height::new(2, 1).unwrap());
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+
+    ctx.ibc_store.delete_consensus_state(path_at(2, 1)).unwrap();
+
+    // Rewrite the history so that the consensus state at height 5 is no more
+    // recent than the one at height 2.
+    let oldest_consensus_state = ctx.ibc_store.consensus_state(&path_at(1, 2)).unwrap();
+    ctx.ibc_store
+        .store_consensus_state(path_at(1, 5), oldest_consensus_state)
+        .unwrap();
+
+    let res = client_state.audit_consensus_history(&ctx.ibc_store, &client_id);
+    match res {
+        Err(AuditError::NonMonotonicTimestamp {
+            previous_height,
+            previous_timestamp,
+            height,
+            timestamp,
+        }) => {
+            assert_eq!(previous_height, Height::new(1, 2).unwrap());
+            assert_eq!(height, Height::new(1, 5).unwrap());
+            assert_eq!(timestamp, previous_timestamp);
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+}
+
 #[rstest]
 fn test_update_nonexisting_client(fixture: Fixture) {
     let Fixture { ctx, router } = fixture;
 
-    let signer = dummy_account_id();
+    let signer = dummy_account_id();
+
+    let msg = MsgUpdateClient {
+        client_id: ClientId::from_str("nonexistingclient").unwrap(),
+        client_message: MockHeader::new(Height::new(0, 46).unwrap()).into(),
+        signer,
+    };
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_adjacent_ok() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let signer = dummy_account_id();
+
+    let block = ctx_b.host_block(&update_height).unwrap();
+    let mut block = block.into_header();
+    block.set_trusted_height(client_height);
+
+    let latest_header_height = block.height();
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer,
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok());
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&msg.client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &msg.client_id)
+        .unwrap()
+        .is_active());
+
+    assert_eq!(client_state.latest_height(), latest_header_height);
+}
+
+/// A tracing layer that records the name and fields of every new span.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<(String, BTreeMap<String, String>)>>>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for SpanRecorder {
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, _id: &span::Id, _ctx: Context<'_, S>) {
+        struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+        impl Visit for FieldVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{value:?}"));
+            }
+        }
+
+        let mut fields = BTreeMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+
+        self.spans
+            .lock()
+            .expect("no poisoned lock")
+            .push((attrs.metadata().name().to_string(), fields));
+    }
+}
+
+#[rstest]
+fn test_verify_header_emits_tracing_span() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let recorder = SpanRecorder::default();
+    let subscriber = tracing_subscriber::registry().with(recorder.clone());
+
+    let res = tracing::subscriber::with_default(subscriber, || {
+        validate(&ctx.ibc_store, &router, msg_envelope)
+    });
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let spans = recorder.spans.lock().expect("no poisoned lock");
+    let (_, fields) = spans
+        .iter()
+        .find(|(name, _)| name == "verify_header")
+        .expect("verify_header span is emitted");
+
+    assert_eq!(fields["client_id"], client_id.to_string());
+    assert_eq!(fields["trusted_height"], client_height.to_string());
+    assert_eq!(fields["target_height"], update_height.to_string());
+}
+
+#[rstest]
+fn test_update_client_with_local_trusted_next_validator_set() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+    let header = TmHeader::from(block);
+
+    // The relayer supplies a bogus trusted next validator set.
+    let relayed_header = TmHeader {
+        trusted_next_validator_set: ValidatorSet::without_proposer(vec![]),
+        ..header.clone()
+    };
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: relayed_header.into(),
+        signer: dummy_account_id(),
+    }));
+
+    // Without a locally available set, the relayer-supplied one is used.
+    assert!(validate(&ctx.ibc_store, &router, msg_envelope.clone()).is_err());
+
+    // The locally available set takes precedence.
+    ctx.ibc_store.trusted_next_validator_sets.lock().insert(
+        (client_id, client_height),
+        header.trusted_next_validator_set,
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+}
+
+#[rstest]
+fn test_verify_header_rejects_chain_id_mismatch() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+    let header = TmHeader::from(block);
+
+    let options = client_state
+        .inner()
+        .as_light_client_options()
+        .expect("valid options");
+
+    let verify = |chain_id: &ChainId| {
+        verify_header::<_, Sha256>(
+            &ctx.ibc_store,
+            &header,
+            &client_id,
+            chain_id,
+            &options,
+            &ProdVerifier::default(),
+        )
+    };
+
+    let res = verify(&chain_id_b);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    // Same revision number, so that only the chain ids differ.
+    let other_chain_id = ChainId::new("mockgaiaC-1").unwrap();
+    match verify(&other_chain_id) {
+        Err(ClientError::HeaderVerificationFailure { reason }) => {
+            assert!(reason.contains(chain_id_b.as_str()), "{reason}");
+            assert!(reason.contains(other_chain_id.as_str()), "{reason}");
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+}
+
+#[rstest]
+fn test_verify_header_requires_adjacent_updates() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height.sub(1).unwrap(), client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let options = client_state
+        .inner()
+        .as_light_client_options()
+        .expect("valid options");
+
+    let header_trusting = |trusted_height: Height| {
+        let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+        block.set_trusted_height(trusted_height);
+        TmHeader::from(block)
+    };
+    let adjacent_header = header_trusting(client_height);
+    let skipping_header = header_trusting(client_height.sub(1).unwrap());
+
+    let verify = |ctx: &MockContext, header: &TmHeader| {
+        verify_header::<_, Sha256>(
+            &ctx.ibc_store,
+            header,
+            &client_id,
+            &chain_id_b,
+            &options,
+            &ProdVerifier::default(),
+        )
+    };
+
+    // Skipping verification is allowed by default.
+    let res = verify(&ctx, &skipping_header);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    ctx.ibc_store.require_adjacent_updates = true;
+
+    let res = verify(&ctx, &adjacent_header);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = verify(&ctx, &skipping_header);
+    assert!(
+        matches!(res, Err(ClientError::HeaderVerificationFailure { .. })),
+        "{res:?}"
+    );
+}
+
+#[rstest]
+#[case(10)]
+#[case(150)]
+fn test_verify_header_with_validators(#[case] validator_set_size: usize) {
+    let HeaderVerificationSetup {
+        ctx,
+        client_id,
+        client_state,
+        header,
+    } = dummy_header_with_validators(validator_set_size);
+
+    assert_eq!(header.validator_set.validators().len(), validator_set_size);
+    assert_eq!(header.trusted_height.increment(), header.height());
+
+    let options = client_state
+        .inner()
+        .as_light_client_options()
+        .expect("valid options");
+
+    let res = verify_header::<_, Sha256>(
+        &ctx.ibc_store,
+        &header,
+        &client_id,
+        &client_state.inner().chain_id,
+        &options,
+        &ProdVerifier::default(),
+    );
+    assert!(res.is_ok(), "result: {res:?}");
+}
+
+#[rstest]
+fn test_verify_header_rejects_too_many_validators() {
+    let HeaderVerificationSetup {
+        mut ctx,
+        client_id,
+        client_state,
+        header,
+    } = dummy_header_with_validators(10);
+
+    let options = client_state
+        .inner()
+        .as_light_client_options()
+        .expect("valid options");
+
+    let verify = |ctx: &MockContext| {
+        verify_header::<_, Sha256>(
+            &ctx.ibc_store,
+            &header,
+            &client_id,
+            &client_state.inner().chain_id,
+            &options,
+            &ProdVerifier::default(),
+        )
+    };
+
+    ctx.ibc_store.max_header_validators = 10;
+    let res = verify(&ctx);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    ctx.ibc_store.max_header_validators = 9;
+    match verify(&ctx) {
+        Err(ClientError::ClientSpecific { description }) => {
+            assert!(description.contains("exceeds the maximum"), "{description}");
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+}
+
+/// Returns the raw values stored under the `clients` prefix, by path.
+fn client_store_snapshot<S: ProvableStore + Debug>(
+    ibc_store: &MockIbcStore<S>,
+) -> BTreeMap<Path, Vec<u8>> {
+    ibc_store
+        .store
+        .get_keys(&"clients".to_owned().into())
+        .into_iter()
+        .filter_map(|key| {
+            let value = ibc_store.store.get(StoreHeight::Pending, &key)?;
+            let path: Path = key.try_into().ok()?;
+            Some((path, value))
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_write_paths_on_update_match_update_state() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let mut expected_paths = client_state.write_paths_on_update(&client_id, update_height);
+    expected_paths.sort();
+
+    let before = client_store_snapshot(&ctx.ibc_store);
+
+    client_state
+        .update_state(&mut ctx.ibc_store, &client_id, block.into())
+        .expect("update succeeds");
+
+    let after = client_store_snapshot(&ctx.ibc_store);
+
+    let written_paths: Vec<Path> = after
+        .into_iter()
+        .filter(|(path, value)| before.get(path) != Some(value))
+        .map(|(path, _)| path)
+        .collect();
+
+    assert_eq!(written_paths, expected_paths);
+}
+
+#[rstest]
+fn test_update_state_stores_consensus_state_at_header_height_and_timestamp() {
+    let HeaderVerificationSetup {
+        mut ctx,
+        client_id,
+        client_state,
+        header,
+    } = dummy_header_with_validators(4);
+
+    let (header_height, header_timestamp): (Height, Timestamp) = (&header).into();
+
+    let updated_heights = client_state
+        .update_state(&mut ctx.ibc_store, &client_id, header.into())
+        .expect("update succeeds");
+    assert_eq!(updated_heights, vec![header_height]);
+
+    let AnyConsensusState::Tendermint(consensus_state) = ctx
+        .ibc_store
+        .consensus_state(&header_height.consensus_state_path(&client_id))
+        .expect("consensus state is stored at the header height")
+    else {
+        panic!("tendermint consensus state");
+    };
+    assert_eq!(
+        Timestamp::from(consensus_state.inner().timestamp),
+        header_timestamp
+    );
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), header_height);
+}
+
+#[rstest]
+fn test_update_state_with_stored_consensus_state_at_header_height() {
+    let HeaderVerificationSetup {
+        mut ctx,
+        client_id,
+        client_state,
+        header,
+    } = dummy_header_with_validators(4);
+
+    let header_height = header.height();
+    let header_consensus_state = TmConsensusStateType::from(header.clone());
+
+    // A consensus state with the same content is left as it is.
+    ctx.ibc_store
+        .store_consensus_state(
+            header_height.consensus_state_path(&client_id),
+            header_consensus_state.clone().into(),
+        )
+        .unwrap();
+
+    client_state
+        .update_state(&mut ctx.ibc_store, &client_id, header.clone().into())
+        .expect("update of an already stored consensus state is a no-op");
+
+    // A conflicting one is rejected.
+    let conflicting_consensus_state = TmConsensusStateType {
+        timestamp: (header_consensus_state.timestamp - Duration::from_secs(1)).unwrap(),
+        ..header_consensus_state
+    };
+    ctx.ibc_store
+        .store_consensus_state(
+            header_height.consensus_state_path(&client_id),
+            conflicting_consensus_state.clone().into(),
+        )
+        .unwrap();
+
+    let res = client_state.update_state(&mut ctx.ibc_store, &client_id, header.into());
+    assert!(
+        matches!(
+            &res,
+            Err(ClientError::ClientSpecific { description })
+                if description.contains("conflicts")
+        ),
+        "{res:?}"
+    );
+
+    let AnyConsensusState::Tendermint(consensus_state) = ctx
+        .ibc_store
+        .consensus_state(&header_height.consensus_state_path(&client_id))
+        .unwrap()
+    else {
+        panic!("tendermint consensus state");
+    };
+    assert_eq!(consensus_state.inner(), &conflicting_consensus_state);
+}
+
+#[rstest]
+fn test_update_state_rejects_consensus_state_from_the_future() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let now = Timestamp::now();
+
+    // Chain B runs an hour ahead of chain A, well beyond the clock drift.
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(update_height)
+        .latest_timestamp((now + Duration::from_secs(60 * 60)).unwrap())
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(now)
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    // Install an expired consensus state, which an accepted update would prune.
+    let expired_height = Height::new(1, 5).unwrap();
+    let AnyConsensusState::Tendermint(consensus_state) = ctx
+        .ibc_store
+        .consensus_state(&client_height.consensus_state_path(&client_id))
+        .unwrap()
+    else {
+        panic!("tendermint consensus state");
+    };
+    let expired_consensus_state = TmConsensusStateType {
+        timestamp: (consensus_state.inner().timestamp - client_state.inner().trusting_period * 2)
+            .unwrap(),
+        ..consensus_state.inner().clone()
+    };
+    ctx.ibc_store
+        .store_consensus_state(
+            expired_height.consensus_state_path(&client_id),
+            expired_consensus_state.into(),
+        )
+        .unwrap();
+    ctx.ibc_store
+        .store_update_meta(
+            client_id.clone(),
+            expired_height,
+            now,
+            Height::new(1, 1).unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        client_state
+            .count_prunable(&ctx.ibc_store, &client_id, now)
+            .unwrap(),
+        1
+    );
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let res = client_state.update_state(&mut ctx.ibc_store, &client_id, block.into());
+    assert!(
+        matches!(
+            &res,
+            Err(ClientError::ClientSpecific { description })
+                if description.contains("too far in the future")
+        ),
+        "{res:?}"
+    );
+
+    assert!(ctx
+        .ibc_store
+        .consensus_state(&update_height.consensus_state_path(&client_id))
+        .is_err());
+
+    // The rejected update did not prune anything either.
+    assert!(ctx
+        .ibc_store
+        .consensus_state(&expired_height.consensus_state_path(&client_id))
+        .is_ok());
+    assert!(ctx
+        .ibc_store
+        .client_update_meta(&client_id, &expired_height)
+        .is_ok());
+}
+
+#[rstest]
+fn test_update_client_stores_update_meta() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    assert!(ctx
+        .ibc_store
+        .client_update_meta(&client_id, &update_height)
+        .is_err());
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let (processed_time, processed_height) = ctx
+        .ibc_store
+        .client_update_meta(&client_id, &update_height)
+        .expect("update meta is stored");
+
+    assert_eq!(
+        processed_time,
+        ValidationContext::host_timestamp(&ctx.ibc_store).unwrap()
+    );
+    assert_eq!(
+        processed_height,
+        ValidationContext::host_height(&ctx.ibc_store).unwrap()
+    );
+}
+
+#[rstest]
+fn test_touch_latest_refreshes_update_meta() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let consensus_state_path = client_height.consensus_state_path(&client_id);
+    let consensus_state_before = ctx
+        .ibc_store
+        .consensus_state(&consensus_state_path)
+        .expect("consensus state exists");
+    let update_meta_before = ctx
+        .ibc_store
+        .client_update_meta(&client_id, &client_height)
+        .expect("update meta is stored");
+
+    ctx.advance_block_height();
+
+    client_state
+        .touch_latest(&mut ctx.ibc_store, &client_id)
+        .expect("touch succeeds");
+
+    let (processed_time, processed_height) = ctx
+        .ibc_store
+        .client_update_meta(&client_id, &client_height)
+        .expect("update meta is stored");
+
+    assert_ne!((processed_time, processed_height), update_meta_before);
+    assert_eq!(
+        processed_time,
+        ValidationContext::host_timestamp(&ctx.ibc_store).unwrap()
+    );
+    assert_eq!(
+        processed_height,
+        ValidationContext::host_height(&ctx.ibc_store).unwrap()
+    );
+
+    assert_eq!(
+        ctx.ibc_store
+            .consensus_state(&consensus_state_path)
+            .expect("consensus state exists"),
+        consensus_state_before
+    );
+
+    // There is no consensus state to touch for an unknown client.
+    assert!(client_state
+        .touch_latest(&mut ctx.ibc_store, &tm_client_type().build_client_id(1))
+        .is_err());
+}
+
+#[rstest]
+fn test_replay_headers() {
+    let genesis_height = Height::new(1, 20).unwrap();
+    let latest_height = Height::new(1, 23).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(latest_height)
+        .build::<TendermintContext>();
+
+    let LightClientState {
+        client_state,
+        consensus_states,
+    } = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([genesis_height])
+        .build();
+
+    let genesis = consensus_states[&genesis_height].inner().clone();
+
+    let mut headers: Vec<TmHeader> = (21..=23)
+        .map(|revision_height| {
+            let height = Height::new(1, revision_height).unwrap();
+            let mut header = ctx_b.host_block(&height).unwrap().into_header();
+            header.set_trusted_height(height.sub(1).unwrap());
+            header.into()
+        })
+        .collect();
+
+    let now = ctx_b.latest_timestamp();
+
+    let accepted_heights = client_state
+        .replay_headers(genesis.clone(), genesis_height, &headers, now)
+        .expect("all headers verify");
+
+    assert_eq!(
+        accepted_heights,
+        headers.iter().map(TmHeader::height).collect::<Vec<_>>()
+    );
+
+    // A header from another chain in the middle is rejected upfront, as it
+    // would be by a single update.
+    let mut foreign_headers = headers.clone();
+    foreign_headers[1].signed_header.header.chain_id = "mockgaiaC-1".parse().unwrap();
+
+    let err = client_state
+        .replay_headers(genesis.clone(), genesis_height, &foreign_headers, now)
+        .expect_err("the header from another chain fails verification");
+
+    assert!(
+        err.to_string()
+            .contains("header #1 at height 1-22: header verification failed"),
+        "{err}"
+    );
+    assert!(
+        err.to_string()
+            .contains("header chain id `mockgaiaC-1` does not match client chain id `mockgaiaB-1`"),
+        "{err}"
+    );
+
+    // Tampering with the middle header invalidates the commit over it.
+    let header = &mut headers[1].signed_header.header;
+    header.time = (header.time + Duration::from_secs(1)).unwrap();
+
+    let err = client_state
+        .replay_headers(genesis, genesis_height, &headers, now)
+        .expect_err("the tampered header fails verification");
+
+    assert!(
+        err.to_string().contains("header #1 at height 1-22"),
+        "{err}"
+    );
+}
+
+#[rstest]
+fn test_update_client_rejects_header_before_trusted_consensus_state() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let mut light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+
+    // Move the trusted consensus state just past the header, which is thus
+    // timestamped in its past.
+    let header_time = block.header().time;
+    let trusted_time = (header_time + Duration::from_secs(1)).unwrap();
+    let trusted_consensus_state = light_client
+        .consensus_states
+        .get_mut(&client_height)
+        .unwrap();
+    *trusted_consensus_state = TmConsensusStateType {
+        timestamp: trusted_time,
+        ..trusted_consensus_state.inner().clone()
+    }
+    .into();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    let err = res.expect_err("header precedes the trusted consensus state");
+    assert!(
+        matches!(
+            err,
+            ContextError::ClientError(ClientError::HeaderTimestampTooOld {
+                header_time: h,
+                trusted_time: t,
+            }) if h == header_time.into() && t == trusted_time.into()
+        ),
+        "{err}"
+    );
+}
+
+#[rstest]
+fn test_update_client_header_conflicting_with_stored_consensus_state() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let chain_b = |latest_timestamp| {
+        TestContextConfig::builder()
+            .host(
+                TendermintHost::builder()
+                    .chain_id(chain_id_b.clone())
+                    .build(),
+            )
+            .latest_height(client_height)
+            .latest_timestamp(latest_timestamp)
+            .build::<TendermintContext>()
+    };
+
+    let ctx_b = chain_b(Timestamp::now());
+    // A fork of chain B, whose blocks are timestamped differently.
+    let ctx_b_fork = chain_b((ctx_b.latest_timestamp() + Duration::from_secs(60)).unwrap());
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let header_at = |ctx: &TendermintContext| -> TmHeader {
+        ctx.host_block(&client_height).unwrap().into_header().into()
+    };
+
+    // The header stored consensus state was derived from doesn't conflict.
+    assert!(!check_for_misbehaviour_on_update(
+        &ctx.ibc_store,
+        header_at(&ctx_b),
+        &client_id,
+        &client_height,
+    )
+    .unwrap());
+
+    // A header of the fork at the same height is evidence of misbehaviour.
+    assert!(check_for_misbehaviour_on_update(
+        &ctx.ibc_store,
+        header_at(&ctx_b_fork),
+        &client_id,
+        &client_height,
+    )
+    .unwrap());
+}
+
+#[rstest]
+fn test_update_client_header_conflicting_next_validators_hash() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let header: TmHeader = ctx_b
+        .host_block(&client_height)
+        .unwrap()
+        .into_header()
+        .into();
+
+    let mut light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+
+    // Store a consensus state agreeing with the header on everything but the
+    // next validators hash.
+    let stored_consensus_state = light_client
+        .consensus_states
+        .get_mut(&client_height)
+        .unwrap();
+    *stored_consensus_state = TmConsensusStateType {
+        next_validators_hash: Hash::Sha256([0xAB; 32]),
+        ..stored_consensus_state.inner().clone()
+    }
+    .into();
+
+    let header_consensus_state = TmConsensusStateType::from(header.clone());
+    assert_eq!(
+        stored_consensus_state.inner().root,
+        header_consensus_state.root
+    );
+    assert_eq!(
+        stored_consensus_state.inner().timestamp,
+        header_consensus_state.timestamp
+    );
+    assert_ne!(
+        stored_consensus_state.inner().next_validators_hash,
+        header_consensus_state.next_validators_hash
+    );
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    assert!(
+        check_for_misbehaviour_on_update(&ctx.ibc_store, header, &client_id, &client_height)
+            .unwrap()
+    );
+}
+
+#[rstest]
+fn test_verify_client_message_rejects_frozen_client() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+    let client_message: Any = block.into();
+
+    let light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let client_state = light_client.client_state.clone();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let frozen = |after_misbehaviour: bool| {
+        let mut client_state = client_state.inner().clone();
+        client_state.frozen_height = FrozenHeight::misbehaviour_sentinel();
+        client_state.allow_update.after_misbehaviour = after_misbehaviour;
+        ClientState::from(client_state)
+    };
+
+    client_state
+        .verify_client_message(&ctx.ibc_store, &client_id, client_message.clone())
+        .expect("header verifies against an active client");
+
+    let res =
+        frozen(false).verify_client_message(&ctx.ibc_store, &client_id, client_message.clone());
+    assert!(
+        matches!(res, Err(ClientError::ClientFrozen { .. })),
+        "{res:?}"
+    );
+
+    frozen(true)
+        .verify_client_message(&ctx.ibc_store, &client_id, client_message)
+        .expect("header verifies when updates after misbehaviour are allowed");
+}
+
+#[rstest]
+#[case::without_after_misbehaviour(false)]
+#[case::with_after_misbehaviour(true)]
+fn test_update_frozen_client(#[case] after_misbehaviour: bool) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let mut light_client = LightClientBuilder::init()
+        .context(&ctx_b)
+        .consensus_heights([client_height])
+        .build();
+    let mut client_state = light_client.client_state.inner().clone();
+    client_state.frozen_height = FrozenHeight::misbehaviour_sentinel();
+    client_state.allow_update.after_misbehaviour = after_misbehaviour;
+    light_client.client_state = client_state.into();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(&client_id, light_client);
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    if !after_misbehaviour {
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ClientError(
+                    ClientError::ClientNotActive { .. }
+                ))
+            ),
+            "{res:?}"
+        );
+        return;
+    }
+
+    res.expect("header for a client allowing updates after misbehaviour validates");
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope).expect("update succeeds");
+
+    // The accepted header revived the client.
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_active());
+}
+
+#[rstest]
+fn test_update_mock_client_rejected_while_paused(fixture: Fixture) {
+    let Fixture { mut ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let height = Height::new(0, 46).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(height)
+            .with_timestamp(Timestamp::now())
+            .into(),
+        signer: dummy_account_id(),
+    }));
 
-    let msg = MsgUpdateClient {
-        client_id: ClientId::from_str("nonexistingclient").unwrap(),
-        client_message: MockHeader::new(Height::new(0, 46).unwrap()).into(),
-        signer,
-    };
+    ctx.ibc_store.pause_client(&client_id).unwrap();
 
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientPaused { .. }))
+        ),
+        "{res:?}"
+    );
 
-    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    ctx.ibc_store.unpause_client(&client_id).unwrap();
 
-    assert!(res.is_err());
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
 }
 
 #[rstest]
-fn test_update_synthetic_tendermint_client_adjacent_ok() {
+fn test_update_client_rejected_while_paused() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
     let update_height = Height::new(1, 21).unwrap();
-    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
     let ctx_b = TestContextConfig::builder()
-        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
         .latest_height(update_height)
         .build::<TendermintContext>();
 
@@ -367,34 +1902,45 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
 
     let mut router = MockRouter::new_with_transfer();
 
-    let signer = dummy_account_id();
-
-    let block = ctx_b.host_block(&update_height).unwrap();
-    let mut block = block.into_header();
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
     block.set_trusted_height(client_height);
 
-    let latest_header_height = block.height();
-    let msg = MsgUpdateClient {
-        client_id,
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
         client_message: block.into(),
-        signer,
-    };
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
-
-    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
-    assert!(res.is_ok());
+        signer: dummy_account_id(),
+    }));
 
-    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
-    assert!(res.is_ok(), "result: {res:?}");
+    ctx.ibc_store.pause_client(&client_id).unwrap();
+    assert!(ctx.ibc_store.is_client_paused(&client_id).unwrap());
 
-    let client_state = ctx.ibc_store.client_state(&msg.client_id).unwrap();
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientPaused { .. }))
+        ),
+        "{res:?}"
+    );
 
+    // Pausing does not affect the status of the client.
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
     assert!(client_state
-        .status(&ctx.ibc_store, &msg.client_id)
+        .status(&ctx.ibc_store, &client_id)
         .unwrap()
         .is_active());
 
-    assert_eq!(client_state.latest_height(), latest_header_height);
+    ctx.ibc_store.unpause_client(&client_id).unwrap();
+    assert!(!ctx.ibc_store.is_client_paused(&client_id).unwrap());
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
 }
 
 #[rstest]
@@ -1199,6 +2745,47 @@ fn test_misbehaviour_client_ok(fixture: Fixture) {
     ensure_misbehaviour(&ctx.ibc_store, &client_id, &mock_client_type());
 }
 
+#[rstest]
+fn test_client_update_and_misbehaviour_hooks(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    for revision_height in 46..49 {
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(Height::new(0, revision_height).unwrap())
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        };
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+        validate(&ctx.ibc_store, &router, msg_envelope.clone()).expect("validation happy path");
+        execute(&mut ctx.ibc_store, &mut router, msg_envelope).expect("execution happy path");
+    }
+
+    let metrics = ctx.ibc_store.client_metrics.lock()[&client_id].clone();
+    assert_eq!(metrics.updates, 3);
+    assert_eq!(metrics.misbehaviours, 0);
+    assert_eq!(
+        metrics.last_updated_height,
+        Some(Height::new(0, 48).unwrap())
+    );
+
+    let msg_envelope = msg_update_client(&client_id);
+
+    validate(&ctx.ibc_store, &router, msg_envelope.clone()).expect("validation happy path");
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope).expect("execution happy path");
+
+    let metrics = ctx.ibc_store.client_metrics.lock()[&client_id].clone();
+    assert_eq!(metrics.updates, 3);
+    assert_eq!(metrics.misbehaviours, 1);
+}
+
 #[rstest]
 fn test_submit_misbehaviour_nonexisting_client(fixture: Fixture) {
     let Fixture { router, .. } = fixture;
@@ -1233,8 +2820,9 @@ fn test_client_update_misbehaviour_nonexisting_client(fixture: Fixture) {
 
 /// Tests misbehaviour handling for the synthetic Tendermint client.
 /// Misbehaviour evidence consists of equivocal headers.
-#[rstest]
-fn test_misbehaviour_synthetic_tendermint_equivocation() {
+/// Builds a context hosting a Tendermint light client, and a message
+/// submitting valid equivocation misbehaviour for it.
+fn synthetic_tendermint_equivocation() -> (MockContext, ClientId, MsgEnvelope) {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
     let misbehaviour_height = Height::new(1, 21).unwrap();
@@ -1267,8 +2855,6 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
                 .build(),
         );
 
-    let mut router_a = MockRouter::new_with_transfer();
-
     // Get chain-B's header at `misbehaviour_height`
     let header1: TmHeader = {
         let block = ctx_b.host_block(&misbehaviour_height).unwrap();
@@ -1295,11 +2881,22 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
 
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
-        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2)
+            .expect("headers are conflicting")
+            .into(),
         signer: dummy_account_id(),
     };
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
+    (ctx_a, client_id, msg_envelope)
+}
+
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_equivocation() {
+    let (mut ctx_a, client_id, msg_envelope) = synthetic_tendermint_equivocation();
+
+    let mut router_a = MockRouter::new_with_transfer();
+
     let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
     assert!(res.is_ok());
     let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
@@ -1307,6 +2904,23 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
     ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
 }
 
+#[rstest]
+fn test_misbehaviour_rejects_too_many_validators() {
+    let (mut ctx_a, _, msg_envelope) = synthetic_tendermint_equivocation();
+
+    let router_a = MockRouter::new_with_transfer();
+
+    // No validator set fits, so both headers of the misbehaviour are too
+    // large.
+    ctx_a.ibc_store.max_header_validators = 0;
+
+    let err = validate(&ctx_a.ibc_store, &router_a, msg_envelope).expect_err("too many validators");
+    assert!(
+        err.to_string().contains("exceeds the maximum"),
+        "unexpected error: {err}"
+    );
+}
+
 #[rstest]
 fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1377,6 +2991,7 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
         client_message: TmMisbehaviour::new(client_id.clone(), header1.into(), header2.into())
+            .expect("headers are conflicting")
             .into(),
         signer: dummy_account_id(),
     };
@@ -1430,7 +3045,7 @@ fn test_expired_client() {
                 .build(),
         );
 
-    while ctx.ibc_store.host_timestamp().expect("no error")
+    while ValidationContext::host_timestamp(&ctx.ibc_store).expect("no error")
         < (timestamp + trusting_period).expect("no error")
     {
         ctx.advance_block_height();
@@ -1444,6 +3059,211 @@ fn test_expired_client() {
         .is_expired());
 }
 
+/// Trusting period of the client built by [`expired_client_with_header`]
+const EXPIRING_CLIENT_TRUSTING_PERIOD: Duration = Duration::from_secs(64);
+
+/// Unbonding period of the client built by [`expired_client_with_header`]
+const EXPIRING_CLIENT_UNBONDING_PERIOD: Duration = Duration::from_secs(128);
+
+/// Builds a context hosting a Tendermint light client whose consensus state
+/// is at least `elapsed` old, hence expired, and a header that is valid with
+/// respect to the expired consensus state.
+fn expired_client_with_header(
+    after_expiry: bool,
+    elapsed: Duration,
+) -> (MockContext, ClientId, TmHeader) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = client_height.increment();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(client_height)
+        .latest_timestamp(timestamp)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(EXPIRING_CLIENT_TRUSTING_PERIOD)
+                        .unbonding_period(EXPIRING_CLIENT_UNBONDING_PERIOD)
+                        .allow_update(AllowUpdate {
+                            after_expiry,
+                            after_misbehaviour: false,
+                        })
+                        .build(),
+                )
+                .build(),
+        );
+
+    while ValidationContext::host_timestamp(&ctx.ibc_store).expect("no error")
+        < (timestamp + elapsed).expect("no error")
+    {
+        ctx.advance_block_height();
+    }
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_expired());
+
+    let header = {
+        let mut tm_block = TendermintHost::builder()
+            .chain_id(chain_id_b)
+            .build()
+            .generate_block(
+                Vec::new(),
+                update_height.revision_height(),
+                ValidationContext::host_timestamp(&ctx.ibc_store).expect("no error"),
+                &Default::default(),
+            )
+            .into_header();
+        tm_block.set_trusted_height(client_height);
+        tm_block.into()
+    };
+
+    (ctx, client_id, header)
+}
+
+#[rstest]
+fn test_revive_expired_client_allowed_after_expiry() {
+    let (mut ctx, client_id, header) =
+        expired_client_with_header(true, EXPIRING_CLIENT_TRUSTING_PERIOD);
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let header_height = header.height();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_active());
+
+    assert_eq!(client_state.latest_height(), header_height);
+}
+
+#[rstest]
+fn test_expired_client_update_rejected_without_after_expiry() {
+    let (ctx, client_id, header) =
+        expired_client_with_header(false, EXPIRING_CLIENT_TRUSTING_PERIOD);
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_revive_expired_client_rejected_past_unbonding_period() {
+    let (ctx, client_id, header) =
+        expired_client_with_header(true, EXPIRING_CLIENT_UNBONDING_PERIOD);
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let err = validate(&ctx.ibc_store, &router, msg_envelope).expect_err("revival is rejected");
+    assert!(
+        err.to_string().contains("unbonding period"),
+        "unexpected error: {err}"
+    );
+}
+
+#[rstest]
+fn test_client_status_unknown_without_latest_consensus_state() {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 18).unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init().context(&ctx_b).build(),
+        );
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_active());
+
+    // Simulate the latest consensus state having been pruned.
+    ctx.ibc_store
+        .delete_consensus_state(client_height.consensus_state_path(&client_id))
+        .unwrap();
+
+    let status = client_state.status(&ctx.ibc_store, &client_id).unwrap();
+
+    assert!(status.is_unknown());
+    assert!(!status.is_expired());
+}
+
 #[rstest]
 fn test_client_update_max_clock_drift() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1485,8 +3305,9 @@ fn test_client_update_max_clock_drift() {
 
     let router_a = MockRouter::new_with_transfer();
 
-    while ctx_b.ibc_store.host_timestamp().expect("no error")
-        < (ctx_a.ibc_store.host_timestamp().expect("no error") + max_clock_drift).expect("no error")
+    while ValidationContext::host_timestamp(&ctx_b.ibc_store).expect("no error")
+        < (ValidationContext::host_timestamp(&ctx_a.ibc_store).expect("no error") + max_clock_drift)
+            .expect("no error")
     {
         ctx_b.advance_block_height();
     }
@@ -1,24 +1,32 @@
-use ibc::clients::tendermint::types::client_type;
+use ibc::clients::tendermint::types::proto::v1::ClientState as RawTmClientState;
+use ibc::clients::tendermint::types::{
+    client_type, ConsensusState as TmConsensusState, TENDERMINT_CLIENT_STATE_TYPE_URL,
+};
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
 use ibc::core::client::context::{ClientValidationContext, ExtClientValidationContext};
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpgradeClient};
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToVec;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
 };
 use ibc_testkit::fixtures::core::client::dummy_msg_upgrade_client;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
+use tendermint::Time;
 
 enum Ctx {
     Default,
@@ -118,11 +126,7 @@ fn upgrade_client_execute(fxt: &mut Fixture<MsgUpgradeClient>, expect: Expect) {
 
             let consensus_state = fxt
                 .ctx
-                .consensus_state(&ClientConsensusStatePath::new(
-                    fxt.msg.client_id.clone(),
-                    plan_height.revision_number(),
-                    plan_height.revision_height(),
-                ))
+                .consensus_state(&plan_height.consensus_state_path(&fxt.msg.client_id))
                 .unwrap();
             let msg_consensus_state: AnyConsensusState =
                 fxt.msg.upgraded_consensus_state.clone().try_into().unwrap();
@@ -170,3 +174,109 @@ fn upgrade_client_fail_unknown_upgraded_client_state() {
     });
     upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+#[test]
+fn tm_upgrade_rejects_degenerate_consensus_state() {
+    let mut ctx = DefaultIbcStore::default();
+    let client_id = client_type().build_client_id(0);
+
+    let tm_header = dummy_tendermint_header();
+    let client_state = dummy_tm_client_state_from_header(tm_header.clone());
+    let upgraded_client_state: Any = client_state.clone().into();
+
+    let upgraded_consensus_state = TmConsensusState::from(tm_header);
+    let zero_timestamp_consensus_state = TmConsensusState::new(
+        upgraded_consensus_state.root(),
+        Time::unix_epoch(),
+        upgraded_consensus_state.next_validators_hash(),
+    );
+
+    assert!(client_state
+        .update_state_on_upgrade(
+            &mut ctx,
+            &client_id,
+            upgraded_client_state.clone(),
+            zero_timestamp_consensus_state.into(),
+        )
+        .is_err());
+    assert!(ctx.client_state(&client_id).is_err());
+
+    client_state
+        .update_state_on_upgrade(
+            &mut ctx,
+            &client_id,
+            upgraded_client_state,
+            upgraded_consensus_state.into(),
+        )
+        .expect("upgrade with a healthy consensus state succeeds");
+    assert!(ctx.client_state(&client_id).is_ok());
+}
+
+#[test]
+fn tm_upgrade_verification_rejects_empty_proof_specs() {
+    let tm_header = dummy_tendermint_header();
+    let client_state = dummy_tm_client_state_from_header(tm_header.clone());
+    let upgraded_consensus_state: Any = TmConsensusState::from(tm_header).into();
+
+    // An in-memory `ProofSpecs` can never be empty, so craft the offending
+    // upgraded client state at the protobuf level.
+    let mut raw_upgraded_client_state = RawTmClientState::from(client_state.inner().clone());
+    raw_upgraded_client_state.proof_specs.clear();
+    let upgraded_client_state = Any {
+        type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+        value: raw_upgraded_client_state.to_vec(),
+    };
+
+    let res = client_state.verify_upgrade_client(
+        upgraded_client_state,
+        upgraded_consensus_state,
+        dummy_commitment_proof_bytes(),
+        dummy_commitment_proof_bytes(),
+        &CommitmentRoot::from_bytes(b"root"),
+    );
+
+    assert!(
+        res.is_err_and(|e| e.to_string().contains("proof specs")),
+        "an upgraded client state with empty proof specs fails to decode"
+    );
+}
+
+#[test]
+fn tm_simulated_upgrade_matches_executed_upgrade() {
+    let mut ctx = DefaultIbcStore::default();
+    let client_id = client_type().build_client_id(0);
+
+    let tm_header = dummy_tendermint_header();
+    let client_state = dummy_tm_client_state_from_header(tm_header.clone());
+    let upgraded_client_state: Any = client_state.clone().into();
+    let upgraded_consensus_state: Any = TmConsensusState::from(tm_header).into();
+
+    let (simulated_client_state, simulated_consensus_state) = client_state
+        .simulate_upgrade(
+            upgraded_client_state.clone(),
+            upgraded_consensus_state.clone(),
+        )
+        .expect("upgrade simulation succeeds");
+
+    // Simulating doesn't store anything.
+    assert!(ctx.client_state(&client_id).is_err());
+
+    let latest_height = client_state
+        .update_state_on_upgrade(
+            &mut ctx,
+            &client_id,
+            upgraded_client_state,
+            upgraded_consensus_state,
+        )
+        .expect("upgrade succeeds");
+
+    assert_eq!(
+        ctx.client_state(&client_id).unwrap(),
+        simulated_client_state.into()
+    );
+    assert_eq!(
+        ctx.consensus_state(&latest_height.consensus_state_path(&client_id))
+            .unwrap(),
+        simulated_consensus_state.into()
+    );
+}
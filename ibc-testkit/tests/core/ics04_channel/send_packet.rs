@@ -3,6 +3,7 @@ use core::time::Duration;
 
 use ibc::core::channel::handler::send_packet;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
@@ -12,6 +13,7 @@ use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::*;
@@ -206,3 +208,132 @@ fn send_packet_processing() {
         }
     }
 }
+
+#[test]
+fn send_packet_without_timeout_requires_opt_in() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let packet: Packet = {
+        let mut packet: Packet = dummy_raw_packet(10, 10).try_into().unwrap();
+        packet.seq_on_a = 1.into();
+        packet.data = vec![0];
+        packet.timeout_height_on_b = TimeoutHeight::no_timeout();
+        packet.timeout_timestamp_on_b = Timestamp::none();
+        packet
+    };
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let res = send_packet(&mut ctx.ibc_store, packet.clone());
+
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(PacketError::MissingTimeout))
+    ));
+    assert!(ctx.get_events().is_empty());
+
+    ctx.ibc_store.allow_packets_without_timeout = true;
+
+    send_packet(&mut ctx.ibc_store, packet).expect("packet without timeout is allowed");
+
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::SendPacket(_))
+    ));
+}
+
+#[test]
+fn send_packet_rejects_oversized_data() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let packet_with_data = |data: Vec<u8>| {
+        let mut packet: Packet = dummy_raw_packet(10, 10).try_into().unwrap();
+        packet.seq_on_a = 1.into();
+        packet.data = data;
+        packet
+    };
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    ctx.ibc_store.max_packet_data_len = 4;
+
+    let res = send_packet(&mut ctx.ibc_store, packet_with_data(vec![0; 5]));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::PacketDataTooLarge {
+                len: 5,
+                max: 4
+            }))
+        ),
+        "{res:?}"
+    );
+    assert!(ctx.get_events().is_empty());
+
+    send_packet(&mut ctx.ibc_store, packet_with_data(vec![0; 4]))
+        .expect("packet data within the limit is allowed");
+
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::SendPacket(_))
+    ));
+}
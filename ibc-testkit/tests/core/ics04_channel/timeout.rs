@@ -13,7 +13,6 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
@@ -157,11 +156,7 @@ fn timeout_fail_no_consensus_state_for_height(fixture: Fixture) {
             packet_commitment,
         );
 
-    let consensus_state_path = ClientConsensusStatePath::new(
-        client_id,
-        msg.proof_height_on_b.revision_number(),
-        msg.proof_height_on_b.revision_height(),
-    );
+    let consensus_state_path = msg.proof_height_on_b.consensus_state_path(&client_id);
 
     ctx.ibc_store
         .delete_consensus_state(consensus_state_path)